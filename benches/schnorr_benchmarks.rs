@@ -13,7 +13,7 @@ use criterion::{BenchmarkId, Criterion, criterion_main, criterion_group};
 
 mod schnorr_benches {
     use super::*;
-    use schnorrkel::{signing_context, verify_batch, Keypair, PublicKey, Signature}; // SecretKey
+    use schnorrkel::{signing_context, verify_batch, Keypair, PreparedPublicKey, PublicKey, Signature}; // SecretKey
 
     // TODO: fn sign_mini(c: &mut Criterion)
 
@@ -38,6 +38,18 @@ mod schnorr_benches {
         });
     }
 
+    fn verify_prepared(c: &mut Criterion) {
+        let keypair: Keypair = Keypair::generate();
+        let msg: &[u8] = b"";
+        let ctx = signing_context(b"this signature does this thing");
+        let sig: Signature = keypair.sign(ctx.bytes(msg));
+        let prepared = PreparedPublicKey::new(&keypair.public);
+
+        c.bench_function("Schnorr signature verification with prepared key", move |b| {
+            b.iter(|| prepared.verify(ctx.bytes(msg), &sig))
+        });
+    }
+
     fn verify_batch_signatures(c: &mut Criterion) {
         const BATCH_SIZES: [usize; 8] = [4, 8, 16, 32, 64, 96, 128, 256];
 
@@ -60,6 +72,35 @@ mod schnorr_benches {
         }
     }
 
+    fn verify_batch_signatures_repeated_keys(c: &mut Criterion) {
+        use rand::seq::SliceRandom;
+
+        const BATCH_SIZES: [usize; 4] = [64, 256, 1024, 4096];
+        const NUM_KEYS: usize = 32;
+
+        let mut group = c.benchmark_group("Schnorr batch signature verification with repeated public keys");
+        for size in &BATCH_SIZES {
+            let keypairs: Vec<Keypair> = (0..NUM_KEYS).map(|_| Keypair::generate()).collect();
+            let msg: &[u8] = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+            let ctx = signing_context(b"this signature does this thing");
+            // Signers in arbitrary order, like transactions in a block.
+            let mut signers: Vec<&Keypair> = keypairs.iter().cycle().take(*size).collect();
+            signers.shuffle(&mut rand::thread_rng());
+            let signatures: Vec<Signature> = signers.iter().map(|key| key.sign(ctx.bytes(msg))).collect();
+            let public_keys: Vec<PublicKey> = signers.iter().map(|key| key.public).collect();
+
+            for dedup in [false, true].iter() {
+                let id = if *dedup { "deduplicated" } else { "not deduplicated" };
+                group.bench_with_input(BenchmarkId::new(id, size), size, |b, &size| {
+                    b.iter(|| {
+                        let transcripts = ::std::iter::once(ctx.bytes(msg)).cycle().take(size);
+                        let _ = verify_batch(transcripts, &signatures[..], &public_keys[..], *dedup);
+                    });
+                });
+            }
+        }
+    }
+
     fn key_generation(c: &mut Criterion) {
         c.bench_function("Schnorr keypair generation", move |b| {
             b.iter(|| Keypair::generate())
@@ -72,7 +113,9 @@ mod schnorr_benches {
         targets =
             sign,
             verify,
+            verify_prepared,
             verify_batch_signatures,
+            verify_batch_signatures_repeated_keys,
             key_generation,
     }
 }
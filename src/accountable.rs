@@ -0,0 +1,241 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Accountable-subgroup multi-signatures
+//!
+//! Any subset of a declared `SignerGroup` cosigns using MuSig, and the
+//! resulting `AccountableSignature` names exactly which members signed
+//! via a compact `SignerBitmap`, as in "Accountable-Subgroup
+//! Multisignatures" by Silvio Micali, Kazuo Ohta, and Leonid Reyzin,
+//! and "Compact Multi-Signatures for Smaller Blockchains" by Dan Boneh,
+//! Manu Drijvers, and Gregory Neven https://eprint.iacr.org/2018/483
+//!
+//! We bind the whole group and the signer bitmap into the transcript
+//! before cosigning, and then run the MuSig protocol from musig.rs
+//! among the signers, so the signature verifies under the aggregate
+//! of the signers' keys weighted by `AggregatePublicKey`.  Verifiers
+//! recompute this aggregate from the group and bitmap alone.  MuSig's
+//! key weighting prevents rogue key attacks among group members, so
+//! a valid signature shows that every member marked in the bitmap
+//! participated.
+//!
+//! All signers must agree upon the bitmap before committing, so if
+//! any signer drops out then the remaining signers must start over.
+
+use core::borrow::{Borrow};
+
+use std::{collections::btree_map::BTreeMap, vec::Vec};
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::musig::{AggregatePublicKey,CommitStage,MuSig};
+
+pub use crate::multisig::SignerBitmap;
+
+
+/// Declared group of potential cosigners, sorted by public key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerGroup {
+    members: Vec<PublicKey>,
+}
+
+impl SignerGroup {
+    /// Declare a group from its members' public keys in any order.
+    ///
+    /// We return `None` if `members` is empty or contains duplicates.
+    pub fn new(mut members: Vec<PublicKey>) -> Option<SignerGroup> {
+        if members.is_empty() { return None; }
+        members.sort_unstable();
+        if members.windows(2).any(|x| x[0]==x[1]) { return None; }
+        Some(SignerGroup { members })
+    }
+
+    /// Group members in sorted order, which determines bitmap indices
+    pub fn members(&self) -> &[PublicKey] { &self.members }
+
+    /// Number of group members
+    pub fn len(&self) -> usize { self.members.len() }
+
+    /// Returns true if this group has no members, which never occurs.
+    pub fn is_empty(&self) -> bool { self.members.is_empty() }
+
+    /// Index of `public_key` within this group, if any
+    pub fn index_of(&self, public_key: &PublicKey) -> Option<usize> {
+        self.members.binary_search(public_key).ok()
+    }
+
+    /// Bitmap marking the given signers, or `None` if some signer
+    /// does not belong to this group.
+    pub fn bitmap<'a,I>(&self, signers: I) -> Option<SignerBitmap>
+    where I: IntoIterator<Item=&'a PublicKey>
+    {
+        let mut bitmap = SignerBitmap::new(self.len());
+        for pk in signers {
+            bitmap.insert(self.index_of(pk) ?);
+        }
+        Some(bitmap)
+    }
+
+    /// Iterate over the members marked in `signers`.
+    pub fn signers<'a>(&'a self, signers: &'a SignerBitmap) -> impl Iterator<Item=&'a PublicKey> + 'a {
+        self.members.iter().enumerate()
+            .filter_map(move |(i,pk)| if signers.contains(i) { Some(pk) } else { None })
+    }
+
+    /// Check that `signers` fits this group and names at least one member.
+    fn check_bitmap(&self, signers: &SignerBitmap) -> SignatureResult<()> {
+        signers.check(self.len()) ?;
+        if signers.count() == 0 {
+            return Err(SignatureError::EquationFalse);
+        }
+        Ok(())
+    }
+
+    /// Aggregate public key of the members marked in `signers`,
+    /// weighted exactly like `MuSig::public_key`.
+    pub fn public_key(&self, signers: &SignerBitmap) -> SignatureResult<PublicKey> {
+        self.check_bitmap(signers) ?;
+        let keys: BTreeMap<&PublicKey,()> = self.signers(signers).map(|pk| (pk,())).collect();
+        Ok(keys.public_key())
+    }
+
+    /// Bind this group and the bitmap of signers into a transcript,
+    /// which signers then cosign using MuSig.
+    pub fn signing_transcript<T>(&self, mut t: T, signers: &SignerBitmap) -> T
+    where T: SigningTranscript
+    {
+        t.proto_name(b"AccountableSubgroup");
+        for pk in self.members.iter() {
+            t.commit_point(b"asm:group", pk.as_compressed());
+        }
+        t.commit_bytes(b"asm:signers", signers.as_bytes());
+        t
+    }
+
+    /// Begin cosigning a transcript as one of the members marked in `signers`.
+    pub fn musig<'k,T>(&self, keypair: &'k Keypair, t: T, signers: &SignerBitmap)
+     -> MuSig<T,CommitStage<&'k Keypair>>
+    where T: SigningTranscript+Clone
+    {
+        keypair.musig(self.signing_transcript(t, signers))
+    }
+
+    /// Verify an accountable-subgroup multi-signature on a transcript
+    /// by the members marked in its bitmap.
+    pub fn verify<T>(&self, t: T, signature: &AccountableSignature) -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        let public_key = self.public_key(&signature.signers) ?;
+        public_key.verify(self.signing_transcript(t, &signature.signers), &signature.signature)
+    }
+}
+
+
+/// Multi-signature by a subgroup of a `SignerGroup`, which names its signers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountableSignature {
+    /// Members who signed
+    signers: SignerBitmap,
+    /// MuSig signature under the signers' aggregate public key
+    signature: Signature,
+}
+
+impl AccountableSignature {
+    const DESCRIPTION : &'static str = "A signer bitmap followed by a 64 byte Ristretto Schnorr signature";
+
+    /// Combine the bitmap of signers with the signature they produced
+    /// using MuSig on `SignerGroup::signing_transcript`.
+    pub fn new<B: Borrow<SignerBitmap>>(signers: B, signature: Signature) -> AccountableSignature {
+        AccountableSignature { signers: signers.borrow().clone(), signature }
+    }
+
+    /// Bitmap of members who signed
+    pub fn signers(&self) -> &SignerBitmap { &self.signers }
+
+    /// Convert this signature to bytes, consisting of the signer
+    /// bitmap followed by the MuSig signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.signers.as_bytes().len() + SIGNATURE_LENGTH);
+        bytes.extend_from_slice(self.signers.as_bytes());
+        bytes.extend_from_slice(&self.signature.to_bytes());
+        bytes
+    }
+
+    /// Construct a signature from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<AccountableSignature> {
+        if bytes.len() < SIGNATURE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "AccountableSignature",
+                description: AccountableSignature::DESCRIPTION,
+                length: 0,
+            });
+        }
+        let (signers, signature) = bytes.split_at(bytes.len() - SIGNATURE_LENGTH);
+        Ok(AccountableSignature {
+            signers: SignerBitmap::from_bytes(signers),
+            signature: Signature::from_bytes(signature) ?,
+        })
+    }
+}
+
+serde_boilerplate!(AccountableSignature);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::musig::{collect_cosignatures,Cosignature,Reveal};
+
+    #[test]
+    fn accountable_subgroup_sign_verify() {
+        let keypairs: Vec<Keypair> = (0..10).map(|_| Keypair::generate()).collect();
+        let group = SignerGroup::new(keypairs.iter().map(|k| k.public).collect()).unwrap();
+        let signing: Vec<&Keypair> = keypairs.iter().step_by(3).collect();
+        let signers = group.bitmap(signing.iter().map(|k| &k.public)).unwrap();
+        assert_eq!(signers.count(), 4);
+
+        let t = signing_context(b"accountable").bytes(b"We are some of legion!");
+        let mut commits: Vec<_> = signing.iter().map(|k| group.musig(k, t.clone(), &signers)).collect();
+        for (k,c) in signing.iter().zip(commits.iter().map(|c| c.our_commitment()).collect::<Vec<_>>()) {
+            for j in commits.iter_mut() {
+                if j.our_commitment() != c { j.add_their_commitment(k.public, c).unwrap(); }
+            }
+        }
+        let mut reveals: Vec<_> = commits.drain(..).map(|c| c.reveal_stage()).collect();
+        let reveal_msgs: Vec<Reveal> = reveals.iter().map(|r| r.our_reveal().clone()).collect();
+        for (k,r) in signing.iter().zip(reveal_msgs.iter()) {
+            for j in reveals.iter_mut() {
+                j.add_their_reveal(k.public, r.clone()).unwrap();
+            }
+        }
+        assert_eq!(reveals[0].public_key(), group.public_key(&signers).unwrap());
+        let cosigns: Vec<_> = reveals.drain(..).map(|r| r.cosign_stage()).collect();
+        let cosign_msgs: Vec<Cosignature> = cosigns.iter().map(|c| c.our_cosignature()).collect();
+
+        let mut c = collect_cosignatures(group.signing_transcript(t.clone(), &signers));
+        for ((k,r),s) in signing.iter().zip(reveal_msgs).zip(cosign_msgs) {
+            c.add(k.public, r, s).unwrap();
+        }
+        let sig = AccountableSignature::new(&signers, c.signature());
+        let sig = AccountableSignature::from_bytes(&sig.to_bytes()[..]).unwrap();
+
+        assert!( group.verify(t.clone(), &sig).is_ok(),
+            "Verification of a valid accountable-subgroup signature failed!" );
+        let mut expected: Vec<PublicKey> = signing.iter().map(|k| k.public).collect();
+        expected.sort_unstable();
+        assert!( group.signers(sig.signers()).eq(expected.iter()) );
+        assert!( group.verify(signing_context(b"accountable").bytes(b"other"), &sig).is_err() );
+
+        // Claiming a different subgroup must fail.
+        let mut wrong = signers.clone();
+        wrong.insert(group.index_of(&keypairs[1].public).unwrap());
+        assert!( group.verify(t.clone(), &AccountableSignature::new(wrong, sig.signature)).is_err(),
+            "Verification with an extra signer in the bitmap passed!" );
+        let fewer = group.bitmap(signing[1..].iter().map(|k| &k.public)).unwrap();
+        assert!( group.verify(t, &AccountableSignature::new(fewer, sig.signature)).is_err(),
+            "Verification with a missing signer in the bitmap passed!" );
+    }
+}
@@ -0,0 +1,228 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Schnorr adaptor signatures, aka pre-signatures, on Ristretto
+//!
+//! An adaptor pre-signature on some transcript is encrypted to an
+//! "encryption point" `T = t B`.  Anyone holding the pre-signature can
+//! check it against `T` using `PublicKey::pre_verify`, and whoever knows
+//! `t` can `adapt` it into an ordinary `Signature`.  Conversely, anyone
+//! who sees both the pre-signature and the resulting `Signature` learns
+//! `t` via `extract_secret`.  We thus obtain the "scriptless script"
+//! atomic swaps and payment channel locks of [1] and [2].
+//!
+//! We reuse the `SigningTranscript` challenge layout from sign.rs,
+//! meaning we commit `R = R' + T` as `sign:R`, so that adapted
+//! signatures verify using `PublicKey::verify` without modification.
+//! We never commit `T` itself, but feed it into our nonce generation
+//! as a witness, so the signer produces independent nonces for
+//! independent encryption points.
+//!
+//! [1] Andrew Poelstra.  "Scriptless Scripts"
+//!     https://download.wpsoftware.net/bitcoin/wizardry/mw-slides/2017-03-mit-bitcoin-expo/slides.pdf
+//! [2] Lloyd Fournier.  "One-Time Verifiably Encrypted Signatures A.K.A. Adaptor Signatures"
+//!     https://github.com/LLFourn/one-time-VES/blob/master/main.pdf
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::points::RistrettoBoth;
+
+
+/// The length of an adaptor `PreSignature`, in bytes.
+pub const PRE_SIGNATURE_LENGTH: usize = 64;
+
+/// A Ristretto Schnorr adaptor pre-signature "detached" from the
+/// signed message and encrypted to some encryption point `T`.
+///
+/// We deliberately leave the schnorrkel marker bit of `Signature`
+/// unset in our serialization, so pre-signatures never deserialize
+/// as `Signature`s.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct PreSignature {
+    /// `R = r B + T` where `T` denotes the encryption point, which
+    /// becomes the `R` of the adapted `Signature`.
+    pub (crate) R: CompressedRistretto,
+    /// `s' = r + c a` which satisfies `s' B = R - T + c A`.
+    pub (crate) s: Scalar,
+}
+
+impl core::fmt::Debug for PreSignature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PreSignature( R: {:?}, s: {:?} )", &self.R, &self.s)
+    }
+}
+
+impl PreSignature {
+    const DESCRIPTION : &'static str = "A 64 byte Ristretto Schnorr adaptor pre-signature";
+
+    /// Convert this `PreSignature` to a byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; PRE_SIGNATURE_LENGTH] {
+        let mut bytes: [u8; PRE_SIGNATURE_LENGTH] = [0u8; PRE_SIGNATURE_LENGTH];
+        bytes[..32].copy_from_slice(&self.R.as_bytes()[..]);
+        bytes[32..].copy_from_slice(&self.s.as_bytes()[..]);
+        bytes
+    }
+
+    /// Construct a `PreSignature` from a slice of bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<PreSignature> {
+        if bytes.len() != PRE_SIGNATURE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "PreSignature",
+                description: PreSignature::DESCRIPTION,
+                length: PRE_SIGNATURE_LENGTH
+            });
+        }
+
+        let mut lower: [u8; 32] = [0u8; 32];
+        let mut upper: [u8; 32] = [0u8; 32];
+        lower.copy_from_slice(&bytes[..32]);
+        upper.copy_from_slice(&bytes[32..]);
+
+        Ok(PreSignature{ R: CompressedRistretto(lower), s: crate::sign::check_scalar(upper) ? })
+    }
+}
+
+serde_boilerplate!(PreSignature);
+
+
+impl SecretKey {
+    /// Produce an adaptor pre-signature on a transcript with this
+    /// `SecretKey`, encrypted to `encryption_point`.
+    ///
+    /// We require the public key corresponding to `self`, like `sign`
+    /// does, and incorporate the encryption point into our nonce
+    /// generation only as a witness.
+    #[allow(non_snake_case)]
+    pub fn pre_sign<T>(&self, mut t: T, public_key: &PublicKey, encryption_point: &RistrettoBoth)
+     -> PreSignature
+    where T: SigningTranscript
+    {
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk",public_key.as_compressed());
+
+        let mut r = t.witness_scalar(b"pre-signing",&[&self.nonce, encryption_point.as_compressed().as_bytes()]);
+        let R = (&r * &constants::RISTRETTO_BASEPOINT_TABLE) + encryption_point.as_point();
+        let R = R.compress();
+
+        t.commit_point(b"sign:R",&R);
+
+        let k: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG+T
+        let s: Scalar = k * self.key + r;
+
+        zeroize::Zeroize::zeroize(&mut r);
+
+        PreSignature{ R, s }
+    }
+}
+
+impl PublicKey {
+    /// Verify an adaptor pre-signature by this public key on a
+    /// transcript, encrypted to `encryption_point`.
+    ///
+    /// If this succeeds then adapting the pre-signature with the
+    /// discrete logarithm of `encryption_point` yields a `Signature`
+    /// that passes `PublicKey::verify` on the same transcript.
+    #[allow(non_snake_case)]
+    pub fn pre_verify<T>(&self, mut t: T, encryption_point: &RistrettoBoth, pre_signature: &PreSignature)
+     -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        let A: &RistrettoPoint = self.as_point();
+
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk",self.as_compressed());
+        t.commit_point(b"sign:R",&pre_signature.R);
+
+        let k: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG+T
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&k, &(-A), &pre_signature.s)
+              + encryption_point.as_point();
+
+        if R.compress() == pre_signature.R { Ok(()) } else { Err(SignatureError::EquationFalse) }
+    }
+}
+
+impl Keypair {
+    /// Produce an adaptor pre-signature on a transcript with this
+    /// keypair's secret key, encrypted to `encryption_point`.
+    pub fn pre_sign<T>(&self, t: T, encryption_point: &RistrettoBoth) -> PreSignature
+    where T: SigningTranscript
+    {
+        self.secret.pre_sign(t, &self.public, encryption_point)
+    }
+
+    /// Verify an adaptor pre-signature by this keypair's public key.
+    pub fn pre_verify<T>(&self, t: T, encryption_point: &RistrettoBoth, pre_signature: &PreSignature)
+     -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        self.public.pre_verify(t, encryption_point, pre_signature)
+    }
+}
+
+/// Adapt a pre-signature into an ordinary `Signature` using the
+/// discrete logarithm `secret` of its encryption point.
+///
+/// We cannot check the result here, so callers should have run
+/// `PublicKey::pre_verify` on the pre-signature beforehand.
+pub fn adapt(pre_signature: &PreSignature, secret: &Scalar) -> Signature {
+    Signature { R: pre_signature.R, s: pre_signature.s + secret }
+}
+
+/// Extract the discrete logarithm of the encryption point from a
+/// pre-signature and the `Signature` adapted from it.
+///
+/// We return `EquationFalse` if `signature` does not share the `R`
+/// of `pre_signature`, and hence cannot have been adapted from it.
+pub fn extract_secret(pre_signature: &PreSignature, signature: &Signature) -> SignatureResult<Scalar> {
+    if pre_signature.R != signature.R {
+        return Err(SignatureError::EquationFalse);
+    }
+    Ok(signature.s - pre_signature.s)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adaptor_pre_sign_adapt_extract() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let adaptor = SecretKey::generate_with(&mut csprng);
+        let encryption_point = RistrettoBoth::from_point(*adaptor.to_public().as_point());
+
+        let ctx = signing_context(b"atomic swap");
+        let pre_sig = keypair.pre_sign(ctx.bytes(b"pay Bob"), &encryption_point);
+        let pre_sig = PreSignature::from_bytes(&pre_sig.to_bytes()[..]).unwrap();
+        assert!( Signature::from_bytes(&pre_sig.to_bytes()[..]).is_err() );
+
+        assert!( keypair.pre_verify(ctx.bytes(b"pay Bob"), &encryption_point, &pre_sig).is_ok(),
+            "Verification of a valid pre-signature failed!" );
+        assert!( keypair.pre_verify(ctx.bytes(b"pay Eve"), &encryption_point, &pre_sig).is_err(),
+            "Verification of a pre-signature on a different message passed!" );
+        let other_point = RistrettoBoth::from_point(*keypair.public.as_point());
+        assert!( keypair.pre_verify(ctx.bytes(b"pay Bob"), &other_point, &pre_sig).is_err(),
+            "Verification of a pre-signature to a different encryption point passed!" );
+
+        let sig = adapt(&pre_sig, &adaptor.key);
+        assert!( keypair.verify(ctx.bytes(b"pay Bob"), &sig).is_ok(),
+            "Verification of an adapted signature failed!" );
+        assert_eq!( extract_secret(&pre_sig, &sig).unwrap(), adaptor.key );
+
+        let unrelated = keypair.sign(ctx.bytes(b"pay Bob"));
+        assert!( extract_secret(&pre_sig, &unrelated).is_err() );
+    }
+}
@@ -49,12 +49,6 @@ where T: SigningTranscript,AEAD: NewAead
 }
 
 impl SecretKey {
-    /// Commit the results of a key exchange into a transcript
-    #[inline(always)]
-    pub(crate) fn raw_key_exchange(&self, public: &PublicKey) -> CompressedRistretto {
-        (&self.key * public.as_point()).compress()
-    }
-
     /// Commit the results of a raw key exchange into a transcript
     pub fn commit_raw_key_exchange<T>(&self, t: &mut T, ctx: &'static [u8], public: &PublicKey)
     where T: SigningTranscript
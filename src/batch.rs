@@ -14,9 +14,11 @@
 use curve25519_dalek::constants;
 use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
 use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::{IsIdentity,VartimeMultiscalarMul};
 
 use super::*;
 use crate::context::{SigningTranscript};
+use crate::vrf::{VRFInOut,VRFProofBatchable};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -27,6 +29,62 @@ use std::vec::Vec;
 const ASSERT_MESSAGE: &'static str = "The number of messages/transcripts, signatures, and public keys must be equal.";
 
 
+/// Map `f` over `items`, using rayon's thread pool if `parallel`
+/// and the `rayon` feature is enabled, but preserving order.
+pub(crate) fn maybe_par_map<A,B,F>(items: Vec<A>, f: F, parallel: bool) -> Vec<B>
+where A: Send, B: Send, F: Fn(A) -> B + Sync + Send
+{
+    #[cfg(feature = "rayon")]
+    if parallel {
+        use rayon::prelude::*;
+        return items.into_par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = parallel;
+    items.into_iter().map(f).collect()
+}
+
+/// Select a random 128-bit scalar for delinearizing one equation.
+/// We may represent these as scalars because we use variable time
+/// 256 bit multiplication.
+pub(crate) fn rnd_128bit_scalar<R: RngCore>(rng: &mut R) -> Scalar {
+    let mut r = [0u8; 16];
+    rng.fill_bytes(&mut r);
+    Scalar::from(u128::from_le_bytes(r))
+}
+
+/// Smallest chunk we hand to one thread for multiscalar multiplication,
+/// below which Pippenger's method loses too much efficiency.
+#[cfg(feature = "rayon")]
+const MIN_PAR_CHUNK: usize = 64;
+
+/// Check that `∑ scalars[i] points[i]` is the identity in variable time,
+/// failing if any point is `None`.
+///
+/// If `parallel` and the `rayon` feature is enabled, then we split the
+/// sum into chunks, one per thread, and add the results, which yields
+/// exactly the same point as the single-threaded multiplication.
+pub(crate) fn multiscalar_is_identity(scalars: Vec<Scalar>, points: Vec<Option<RistrettoPoint>>, parallel: bool) -> bool
+{
+    assert!(scalars.len() == points.len());
+    #[cfg(feature = "rayon")]
+    if parallel {
+        use rayon::prelude::*;
+        use curve25519_dalek::traits::Identity;
+        let threads = rayon::current_num_threads();
+        let chunk = (scalars.len() / threads + 1).max(MIN_PAR_CHUNK);
+        return scalars.par_chunks(chunk).zip(points.par_chunks(chunk))
+            .map(|(s,p)| RistrettoPoint::optional_multiscalar_mul(s, p.iter().cloned()))
+            .reduce(|| Some(RistrettoPoint::identity()), |a,b| a.and_then(|a| b.map(|b| a + b)))
+            .map(|id| id.is_identity()).unwrap_or(false);
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = parallel;
+    RistrettoPoint::optional_multiscalar_mul(scalars, points)
+        .map(|id| id.is_identity()).unwrap_or(false)
+}
+
+
 /// Verify a batch of `signatures` on `messages` with their respective `public_keys`.
 ///
 /// # Inputs
@@ -139,6 +197,42 @@ where
 
     let (zs, hrams) = prepare_batch(transcripts, signatures, public_keys, rng);
 
+    verify_batch_prepared(zs, hrams, signatures, public_keys, deduplicate_public_keys, false)
+}
+
+/// Verify a batch of `signatures` on `messages` with their respective
+/// `public_keys`, splitting the work across rayon's thread pool.
+///
+/// Inputs and return agree with `verify_batch`, except our transcripts
+/// must be `Send` so that we may hash them on many threads.  We check
+/// exactly the same equation as `verify_batch`, so both always agree.
+#[cfg(feature = "rayon")]
+pub fn verify_batch_parallel<T,I>(
+    transcripts: I,
+    signatures: &[Signature],
+    public_keys: &[PublicKey],
+    deduplicate_public_keys: bool,
+) -> SignatureResult<()>
+where
+    T: SigningTranscript+Send,
+    I: IntoIterator<Item=T>,
+{
+    assert!(signatures.len() == public_keys.len(), "{}", ASSERT_MESSAGE);  // Check transcripts length below
+
+    let (zs, hrams) = prepare_batch_parallel(transcripts, signatures, public_keys, rand_hack());
+
+    verify_batch_prepared(zs, hrams, signatures, public_keys, deduplicate_public_keys, true)
+}
+
+fn verify_batch_prepared(
+    zs: Vec<Scalar>,
+    hrams: Vec<Scalar>,
+    signatures: &[Signature],
+    public_keys: &[PublicKey],
+    deduplicate_public_keys: bool,
+    parallel: bool,
+) -> SignatureResult<()>
+{
     // Compute the basepoint coefficient, ∑ s[i]z[i] (mod l)
     let bs: Scalar = signatures.iter()
         .map(|sig| sig.s)
@@ -146,11 +240,77 @@ where
         .map(|(s, z)| z * s)
         .sum();
 
-    verify_batch_equation( bs, zs, hrams, signatures, public_keys, deduplicate_public_keys )
+    verify_batch_equation( bs, zs, hrams, signatures, public_keys, deduplicate_public_keys, parallel )
 }
 
 
-trait HasR {
+/// Verify a batch of `signatures` on `messages` with their respective
+/// `public_keys`, and identify any invalid signatures.
+///
+/// We return the indices of all invalid signatures in increasing order,
+/// so an empty `Vec` means the whole batch verifies.  We first verify
+/// the whole batch, and only upon failure bisect it recursively, reusing
+/// the delinearization scalars and challenge hashes computed once by
+/// `prepare_batch`.  We skip the right half whenever the left half
+/// verifies, because the batch equation for any range is the sum of
+/// those for its halves.  We thus need roughly `2 log(n)` multiscalar
+/// multiplications of decreasing size per invalid signature.
+///
+/// Inputs agree with `verify_batch`, as does the panic behavior.
+pub fn verify_batch_identify<T,I>(
+    transcripts: I,
+    signatures: &[Signature],
+    public_keys: &[PublicKey],
+    deduplicate_public_keys: bool,
+) -> Vec<usize>
+where
+    T: SigningTranscript,
+    I: IntoIterator<Item=T>,
+{
+    assert!(signatures.len() == public_keys.len(), "{}", ASSERT_MESSAGE);  // Check transcripts length below
+
+    let (zs, hrams) = prepare_batch(transcripts, signatures, public_keys, rand_hack());
+
+    let check = |r: core::ops::Range<usize>| {
+        // Compute the basepoint coefficient, ∑ s[i]z[i] (mod l)
+        let bs: Scalar = signatures[r.clone()].iter()
+            .map(|sig| sig.s)
+            .zip(zs[r.clone()].iter())
+            .map(|(s, z)| z * s)
+            .sum();
+        verify_batch_equation(
+            bs, zs[r.clone()].to_vec(), hrams[r.clone()].to_vec(),
+            &signatures[r.clone()], &public_keys[r], deduplicate_public_keys, false
+        ).is_ok()
+    };
+
+    let mut bad = Vec::new();
+    if signatures.is_empty() || check(0..signatures.len()) {
+        return bad;
+    }
+    // Ranges known to contain an invalid signature, visited left to right.
+    let mut failing = Vec::new();
+    failing.push(0..signatures.len());
+    while let Some(r) = failing.pop() {
+        if r.len() == 1 {
+            bad.push(r.start);
+            continue;
+        }
+        let mid = r.start + r.len() / 2;
+        let (left, right) = (r.start..mid, mid..r.end);
+        if check(left.clone()) {
+            failing.push(right);
+        } else {
+            if ! check(right.clone()) {
+                failing.push(right);
+            }
+            failing.push(left);
+        }
+    }
+    bad
+}
+
+trait HasR: Sync {
     #[allow(non_snake_case)]
     fn get_R(&self) -> &CompressedRistretto;
 }
@@ -165,19 +325,85 @@ impl HasR for CompressedRistretto {
 
 /// First phase of batch verification that computes the delinierizing
 /// coefficents and challenge hashes
-#[allow(non_snake_case)]
 fn prepare_batch<T,I,R>(
     transcripts: I,
     signatures: &[impl HasR],
     public_keys: &[PublicKey],
-    mut rng: R,
+    rng: R,
 ) -> (Vec<Scalar>,Vec<Scalar>)
 where
     T: SigningTranscript,
     I: IntoIterator<Item=T>,
     R: RngCore+CryptoRng,
 {
+    let ts = collect_transcripts(transcripts, signatures.len(), public_keys.len());
+    let dhrams: Vec<([u8; 16],Scalar)> = ts.into_iter().map( |(t,i)|
+        batch_challenge(t, &public_keys[i], signatures[i].get_R())
+    ).collect();
+    delinearize_batch(dhrams, signatures, public_keys, rng)
+}
 
+/// First phase of batch verification, like `prepare_batch`, except we
+/// hash the transcripts in parallel using rayon.
+#[cfg(feature = "rayon")]
+fn prepare_batch_parallel<T,I,R>(
+    transcripts: I,
+    signatures: &[impl HasR],
+    public_keys: &[PublicKey],
+    rng: R,
+) -> (Vec<Scalar>,Vec<Scalar>)
+where
+    T: SigningTranscript+Send,
+    I: IntoIterator<Item=T>,
+    R: RngCore+CryptoRng,
+{
+    let ts = collect_transcripts(transcripts, signatures.len(), public_keys.len());
+    let dhrams: Vec<([u8; 16],Scalar)> = maybe_par_map(ts, |(t,i)|
+        batch_challenge(t, &public_keys[i], signatures[i].get_R())
+    , true);
+    delinearize_batch(dhrams, signatures, public_keys, rng)
+}
+
+/// Pair each transcript with its index, checking that we have exactly
+/// one per signature and public key.
+fn collect_transcripts<T,I>(transcripts: I, signatures: usize, public_keys: usize) -> Vec<(T,usize)>
+where I: IntoIterator<Item=T>,
+{
+    // We collect here so that rayon may hash the transcripts in parallel,
+    // but right now you cannot have
+    //   IntoIterator<Item=T, IntoIter: ExactSizeIterator+TrustedLen>
+    let mut transcripts = transcripts.into_iter();
+    let ts: Vec<(T,usize)> = transcripts.by_ref().zip(0..signatures).collect();
+    assert!(transcripts.next().is_none(), "{}", ASSERT_MESSAGE);
+    assert!(ts.len() == public_keys, "{}", ASSERT_MESSAGE);
+    ts
+}
+
+/// Compute H(R || A || M) for one (signature, public_key, message) triplet,
+/// along with the transcript's contribution to our delinearization.
+#[allow(non_snake_case)]
+fn batch_challenge<T>(mut t: T, public_key: &PublicKey, R: &CompressedRistretto) -> ([u8; 16],Scalar)
+where T: SigningTranscript,
+{
+    let mut d = [0u8; 16];
+    t.witness_bytes_rng(b"", &mut d, &[&[]], NotAnRng);  // Could speed this up using ZeroRng
+
+    t.proto_name(b"Schnorr-sig");
+    t.commit_point(b"sign:pk",public_key.as_compressed());
+    t.commit_point(b"sign:R",R);
+    (d, t.challenge_scalar(b"sign:c"))  // context, message, A/public_key, R=rG
+}
+
+/// Derive the delinearizing coefficients from the public keys, signatures,
+/// and transcripts, returning them alongside the challenge hashes.
+fn delinearize_batch<R>(
+    dhrams: Vec<([u8; 16],Scalar)>,
+    signatures: &[impl HasR],
+    public_keys: &[PublicKey],
+    mut rng: R,
+) -> (Vec<Scalar>,Vec<Scalar>)
+where R: RngCore+CryptoRng,
+{
     // Assumulate public keys, signatures, and transcripts for pseudo-random delinearization scalars
     let mut zs_t = merlin::Transcript::new(b"V-RNG");
     for pk in public_keys {
@@ -186,38 +412,16 @@ where
     for sig in signatures {
         zs_t.commit_point(b"",sig.get_R());
     }
-
-    // We might collect here anyways, but right now you cannot have
-    //   IntoIterator<Item=T, IntoIter: ExactSizeIterator+TrustedLen>
-    let mut transcripts = transcripts.into_iter();
-    // Compute H(R || A || M) for each (signature, public_key, message) triplet
-    let hrams: Vec<Scalar> = transcripts.by_ref()
-        .zip(0..signatures.len())
-        .map( |(mut t,i)| {
-            let mut d = [0u8; 16];
-            t.witness_bytes_rng(b"", &mut d, &[&[]], NotAnRng);  // Could speed this up using ZeroRng
-            zs_t.append_message(b"",&d);
-
-            t.proto_name(b"Schnorr-sig");
-            t.commit_point(b"sign:pk",public_keys[i].as_compressed());
-            t.commit_point(b"sign:R",signatures[i].get_R());
-            t.challenge_scalar(b"sign:c")  // context, message, A/public_key, R=rG
-        } ).collect();
-    assert!(transcripts.next().is_none(), "{}", ASSERT_MESSAGE);
-    assert!(hrams.len() == public_keys.len(), "{}", ASSERT_MESSAGE);
+    let hrams: Vec<Scalar> = dhrams.iter().map( |(d,hram)| {
+        zs_t.append_message(b"",d);
+        *hram
+    } ).collect();
 
     // Use a random number generator keyed by both the public keys,
     // and the system random number generator
     let mut csprng = zs_t.build_rng().finalize(&mut rng);
     // Select a random 128-bit scalar for each signature.
-    // We may represent these as scalars because we use
-    // variable time 256 bit multiplication below.
-    let rnd_128bit_scalar = |_| {
-        let mut r = [0u8; 16];
-        csprng.fill_bytes(&mut r);
-        Scalar::from(u128::from_le_bytes(r))
-    };
-    let zs: Vec<Scalar> = signatures.iter().map(rnd_128bit_scalar).collect();
+    let zs: Vec<Scalar> = signatures.iter().map(|_| rnd_128bit_scalar(&mut csprng)).collect();
 
     (zs, hrams)
 }
@@ -231,11 +435,9 @@ fn verify_batch_equation(
     signatures: &[impl HasR],
     public_keys: &[PublicKey],
     deduplicate_public_keys: bool,
+    parallel: bool,
 ) -> SignatureResult<()>
 {
-    use curve25519_dalek::traits::IsIdentity;
-    use curve25519_dalek::traits::VartimeMultiscalarMul;
-
     use core::iter::once;
 
     let B = once(Some(constants::RISTRETTO_BASEPOINT_POINT));
@@ -250,28 +452,34 @@ fn verify_batch_equation(
         }
         public_keys
     } else {
-        // TODO: Actually deduplicate all if deduplicate_public_keys is set?
+        // Sort indices by public key using the `RistrettoBoth` `Ord`,
+        // so equal keys become adjacent, and then merge each run of
+        // equal keys into one term.  Sorting compares only compressed
+        // points, which costs nothing next to the multiscalar multiplication.
+        let mut order: Vec<usize> = (0..public_keys.len()).collect();
+        order.sort_unstable_by_key(|i| &public_keys[*i]);
         ppks.reserve( public_keys.len() );
+        let mut zhrams = Vec::with_capacity( public_keys.len() );
         // Multiply each H(R || A || M) by the random value
-        for i in 0..public_keys.len() {
+        for i in order {
             let zhram = &hrams[i] * zs[i];
-            let j = ppks.len().checked_sub(1);
-            if j.is_none() || ppks[j.unwrap()] != public_keys[i] {
+            if ppks.last() != Some(&public_keys[i]) {
                 ppks.push(public_keys[i]);
-                hrams[ppks.len()-1] = zhram;
+                zhrams.push(zhram);
             } else {
-                hrams[ppks.len()-1] = &hrams[ppks.len()-1] + zhram;
+                *zhrams.last_mut().unwrap() += zhram;
             }
         }
-        hrams.truncate(ppks.len());
+        hrams = zhrams;
         ppks.as_slice()
     }.iter().map(|pk| Some(pk.as_point().clone()));
 
     // Compute (-∑ z[i]s[i] (mod l)) B + ∑ z[i]R[i] + ∑ (z[i]H(R||A||M)[i] (mod l)) A[i] = 0
-    let b = RistrettoPoint::optional_multiscalar_mul(
-        once(-bs).chain(zs.iter().cloned()).chain(hrams),
-        B.chain(Rs).chain(As),
-    ).map(|id| id.is_identity()).unwrap_or(false);
+    let b = multiscalar_is_identity(
+        once(-bs).chain(zs.iter().cloned()).chain(hrams).collect(),
+        B.chain(Rs).chain(As).collect(),
+        parallel,
+    );
     // We need not return SignatureError::PointDecompressionError because
     // the decompression failures occur for R represent invalid signatures.
 
@@ -279,6 +487,165 @@ fn verify_batch_equation(
 }
 
 
+/// Incremental batch verifier for Schnorr signatures and VRF proofs.
+///
+/// We accept signatures and VRF outputs one at a time, perhaps while
+/// they stream in off the network, and do all hashing then, so that
+/// `finalize` only selects the random 128-bit delinearization scalars
+/// like `prepare_batch` does, and runs one multiscalar multiplication.
+/// We store the verification equations' scalars and points already
+/// laid out for this multiscalar multiplication, so `finalize` only
+/// rescales scalars in place.
+///
+/// ```
+/// use schnorrkel::{BatchVerifier,Keypair,signing_context};
+///
+/// let ctx = signing_context(b"some batch");
+/// let mut batch = BatchVerifier::new();
+/// for i in 0..16u64 {
+///     let keypair = Keypair::generate();
+///     let sig = keypair.sign(ctx.bytes(&i.to_le_bytes()));
+///     batch.push_signature(ctx.bytes(&i.to_le_bytes()), &sig, &keypair.public);
+/// }
+/// assert!( batch.finalize(rand_core::OsRng).is_ok() );
+/// ```
+pub struct BatchVerifier {
+    /// Transcript keyed by all items, from which we derive the random weights.
+    zs_t: merlin::Transcript,
+    /// Unweighted scalars for our multiscalar multiplication
+    scalars: Vec<Scalar>,
+    /// Points for our multiscalar multiplication, with `None` for
+    /// any that failed to decompress.
+    points: Vec<Option<RistrettoPoint>>,
+    /// End of each equation's terms in `scalars` and `points`,
+    /// along with the equation's unweighted basepoint coefficient.
+    equations: Vec<(usize, Scalar)>,
+    /// Number of signatures plus VRF proofs pushed
+    len: usize,
+}
+
+impl Default for BatchVerifier {
+    fn default() -> BatchVerifier { BatchVerifier::new() }
+}
+
+impl BatchVerifier {
+    /// Create an empty batch verifier.
+    pub fn new() -> BatchVerifier {
+        BatchVerifier::with_capacity(0)
+    }
+
+    /// Create an empty batch verifier with space for `n` signatures,
+    /// or `n/2` VRF proofs, before reallocating.
+    pub fn with_capacity(n: usize) -> BatchVerifier {
+        BatchVerifier {
+            zs_t: merlin::Transcript::new(b"V-RNG"),
+            scalars: Vec::with_capacity(2*n + 1),
+            points: Vec::with_capacity(2*n + 1),
+            equations: Vec::with_capacity(n),
+            len: 0,
+        }
+    }
+
+    /// Number of signatures plus VRF proofs in this batch.
+    pub fn len(&self) -> usize { self.len }
+
+    /// Returns true if we have neither signatures nor VRF proofs.
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    fn push_equation<S>(&mut self, terms: S, basepoint_coefficient: Scalar)
+    where S: IntoIterator<Item=(Scalar,Option<RistrettoPoint>)>
+    {
+        for (scalar, point) in terms {
+            self.scalars.push(scalar);
+            self.points.push(point);
+        }
+        self.equations.push((self.scalars.len(), basepoint_coefficient));
+    }
+
+    /// Add a signature by `public_key` on transcript `t` to the batch.
+    #[allow(non_snake_case)]
+    pub fn push_signature<T>(&mut self, mut t: T, signature: &Signature, public_key: &PublicKey)
+    where T: SigningTranscript
+    {
+        let mut d = [0u8; 16];
+        t.witness_bytes_rng(b"", &mut d, &[&[]], NotAnRng);
+        self.zs_t.commit_point(b"",public_key.as_compressed());
+        self.zs_t.commit_point(b"",&signature.R);
+        self.zs_t.append_message(b"",&d);
+
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk",public_key.as_compressed());
+        t.commit_point(b"sign:R",&signature.R);
+        let hram = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
+
+        // -s B + R + H(R||A||M) A = 0
+        self.push_equation([
+            (Scalar::one(), signature.R.decompress()),
+            (hram, Some(*public_key.as_point())),
+        ], -signature.s);
+        self.len += 1;
+    }
+
+    /// Add a batchable VRF proof by `public_key` for the VRF input
+    /// and output `p` to the batch, like `vrf_verify_batch` does.
+    ///
+    /// We recompute the challenge here, so callers should obtain `p`
+    /// using `VRFPreOut::attach_input_hash`.
+    #[allow(non_snake_case)]
+    pub fn push_vrf(&mut self, p: &VRFInOut, proof: &VRFProofBatchable, public_key: &PublicKey) {
+        self.zs_t.commit_point(b"",public_key.as_compressed());
+        p.commit(&mut self.zs_t);
+        self.zs_t.commit_point(b"",&proof.R);
+        self.zs_t.commit_point(b"",&proof.Hr);
+
+        let c = proof.shorten_dleq(merlin::Transcript::new(b"VRF"), public_key, p, crate::vrf::KUSAMA_VRF).c;
+
+        // s B + c A - R = 0
+        self.push_equation([
+            (-Scalar::one(), proof.R.decompress()),
+            (c, Some(*public_key.as_point())),
+        ], proof.s);
+        // s Input + c Output - Hr = 0
+        self.push_equation([
+            (-Scalar::one(), proof.Hr.decompress()),
+            (c, Some(*p.output.as_point())),
+            (proof.s, Some(*p.input.as_point())),
+        ], Scalar::zero());
+        self.len += 1;
+    }
+
+    /// Verify every signature and VRF proof in the batch at once,
+    /// using the supplied random number generator along with all
+    /// items to select the random weights.
+    pub fn finalize<R: RngCore+CryptoRng>(self, mut rng: R) -> SignatureResult<()> {
+        let BatchVerifier { zs_t, mut scalars, mut points, equations, .. } = self;
+
+        // Use a random number generator keyed by all items, and the
+        // supplied random number generator
+        let mut csprng = zs_t.build_rng().finalize(&mut rng);
+
+        let mut start = 0;
+        let mut bs = Scalar::zero();
+        for (end, b) in equations {
+            let z = rnd_128bit_scalar(&mut csprng);
+            for scalar in scalars[start..end].iter_mut() {
+                *scalar *= z;
+            }
+            bs += z * b;
+            start = end;
+        }
+        scalars.push(bs);
+        points.push(Some(constants::RISTRETTO_BASEPOINT_POINT));
+
+        if multiscalar_is_identity(scalars, points, false) {
+            Ok(())
+        } else {
+            Err(SignatureError::EquationFalse)
+        }
+    }
+}
+
+
 
 /// Half-aggregated aka prepared batch signature
 /// 
@@ -340,7 +707,7 @@ impl PreparedBatch{
             self.bs,
             zs, hrams,
             self.Rs.as_slice(),
-            public_keys, deduplicate_public_keys
+            public_keys, deduplicate_public_keys, false
         )
     }
 
@@ -392,6 +759,316 @@ impl PreparedBatch{
 }
 
 
+/// Length of the header of an encoded `HalfAggregateSignature`,
+/// consisting of its signature count, `s`, and merge commitment.
+const HALF_AGGREGATE_HEADER_LENGTH: usize = 4 + 32 + 32;
+
+/// Bytes required to pack `bits` bits.
+fn packed_bits_len(bits: usize) -> usize {
+    bits / 8 + (bits % 8 != 0) as usize
+}
+
+/// Recompute the challenge `H(R || A || M)` of one signature.
+#[allow(non_snake_case)]
+fn signature_challenge<T>(mut t: T, public_key: &PublicKey, R: &CompressedRistretto) -> Scalar
+where T: SigningTranscript
+{
+    t.proto_name(b"Schnorr-sig");
+    t.commit_point(b"sign:pk",public_key.as_compressed());
+    t.commit_point(b"sign:R",R);
+    t.challenge_scalar(b"sign:c")  // context, message, A/public_key, R=rG
+}
+
+/// Commit to one signature inside a `HalfAggregateSignature`.
+#[allow(non_snake_case)]
+fn half_aggregate_leaf(public_key: &PublicKey, R: &CompressedRistretto, hram: &Scalar) -> [u8; 32] {
+    let mut t = merlin::Transcript::new(b"HalfAgg-leaf");
+    t.commit_point(b"pk",public_key.as_compressed());
+    t.commit_point(b"R",R);
+    t.append_message(b"c",hram.as_bytes());
+    let mut c = [0u8; 32];
+    t.challenge_bytes(b"commitment",&mut c);
+    c
+}
+
+/// Merge the commitments to two `HalfAggregateSignature`s, returning
+/// the 128-bit weight for the right one and the merged commitment.
+fn half_aggregate_merge(left: &[u8; 32], right: &[u8; 32]) -> (Scalar,[u8; 32]) {
+    let mut t = merlin::Transcript::new(b"HalfAgg-merge");
+    t.append_message(b"left",left);
+    t.append_message(b"right",right);
+    let mut w = [0u8; 16];
+    t.challenge_bytes(b"weight",&mut w);
+    let mut c = [0u8; 32];
+    t.challenge_bytes(b"commitment",&mut c);
+    (Scalar::from(u128::from_le_bytes(w)), c)
+}
+
+/// Half-aggregated signature supporting incremental aggregation.
+///
+/// Like `PreparedBatch`, we replace the `s` of many signatures by one
+/// weighted sum `∑ z[i] s[i]`, but we cannot derive the `z[i]` from
+/// every signature at once because we support adding signatures later.
+/// Instead, we record a binary tree of merges in which merging two
+/// aggregates weights the right one by a 128-bit scalar derived from
+/// commitments to the public keys, `R`s, and challenges of both sides.
+/// Appending single signatures with `push` then reproduces the prefix
+/// weighting of the incremental half-aggregation by Chen and Zhao,
+/// while `merge` combines aggregates produced independently, say by
+/// different block producers.
+///
+/// As in "Non-interactive half-aggregation of EdDSA and variants of
+/// Schnorr signatures" by Chalkias, Garillot, Kondi, and Nikolaenko,
+/// the result takes 32 bytes per signature plus a small constant, and
+/// two bits per signature that encode the merge tree.
+///
+/// ```
+/// use schnorrkel::{HalfAggregateSignature,Keypair,signing_context};
+///
+/// let ctx = signing_context(b"some block");
+/// let keypairs: Vec<Keypair> = (0..4).map(|_| Keypair::generate()).collect();
+/// let msgs: [&[u8]; 4] = [b"tx0", b"tx1", b"tx2", b"tx3"];
+///
+/// let mut agg = HalfAggregateSignature::new();
+/// let mut other = HalfAggregateSignature::new();
+/// for (i, (keypair, msg)) in keypairs.iter().zip(msgs.iter()).enumerate() {
+///     let sig = keypair.sign(ctx.bytes(msg));
+///     let target = if i < 2 { &mut agg } else { &mut other };
+///     target.push(ctx.bytes(msg), &sig, &keypair.public);
+/// }
+/// agg.merge(other);
+///
+/// let public_keys: Vec<_> = keypairs.iter().map(|keypair| keypair.public).collect();
+/// let transcripts = msgs.iter().map(|m| ctx.bytes(m));
+/// assert!( agg.verify(transcripts, &public_keys[..], false).is_ok() );
+/// ```
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HalfAggregateSignature {
+    /// Weighted sum of the `s` of all aggregated signatures.
+    s: Scalar,
+    /// The `R` of each aggregated signature, in order.
+    Rs: Vec<CompressedRistretto>,
+    /// Our merge tree in post-order, with `false` for signatures
+    /// and `true` for merges of the two preceding subtrees.
+    shape: Vec<bool>,
+    /// Commitment to our merge tree, from which we derive the weight
+    /// of future merges, or all zeros when empty.
+    commitment: [u8; 32],
+}
+
+impl HalfAggregateSignature {
+    const DESCRIPTION : &'static str = "A half-aggregated Ristretto Schnorr signature";
+
+    /// Create an empty half-aggregated signature.
+    pub fn new() -> HalfAggregateSignature {
+        HalfAggregateSignature::default()
+    }
+
+    /// Create a half-aggregated signature containing only `signature`.
+    ///
+    /// We cannot check `signature` here, so callers should verify it
+    /// first, as otherwise the whole aggregate fails verification.
+    pub fn from_signature<T>(t: T, signature: &Signature, public_key: &PublicKey) -> HalfAggregateSignature
+    where T: SigningTranscript
+    {
+        let hram = signature_challenge(t, public_key, &signature.R);
+        HalfAggregateSignature {
+            s: signature.s,
+            Rs: core::iter::once(signature.R).collect(),
+            shape: core::iter::once(false).collect(),
+            commitment: half_aggregate_leaf(public_key, &signature.R, &hram),
+        }
+    }
+
+    /// Number of signatures aggregated here.
+    pub fn len(&self) -> usize {
+        self.Rs.len()
+    }
+
+    /// Returns true if we aggregate no signatures.
+    pub fn is_empty(&self) -> bool {
+        self.Rs.is_empty()
+    }
+
+    /// Add one signature on the transcript `t` by `public_key`.
+    ///
+    /// As with `from_signature`, callers should verify `signature` first.
+    pub fn push<T>(&mut self, t: T, signature: &Signature, public_key: &PublicKey)
+    where T: SigningTranscript
+    {
+        self.merge(HalfAggregateSignature::from_signature(t, signature, public_key));
+    }
+
+    /// Append all signatures aggregated in `other` after ours.
+    pub fn merge(&mut self, other: HalfAggregateSignature) {
+        if other.is_empty() { return; }
+        if self.is_empty() {
+            *self = other;
+            return;
+        }
+        let (w, commitment) = half_aggregate_merge(&self.commitment, &other.commitment);
+        self.s += w * other.s;
+        self.Rs.extend(other.Rs);
+        self.shape.extend(other.shape);
+        self.shape.push(true);
+        self.commitment = commitment;
+    }
+
+    /// Verify a half-aggregated signature, given the transcripts and
+    /// public keys of the aggregated signatures in aggregation order.
+    #[allow(non_snake_case)]
+    pub fn verify<T,I>(
+        &self,
+        transcripts: I,
+        public_keys: &[PublicKey],
+        deduplicate_public_keys: bool,
+    ) -> SignatureResult<()>
+    where
+        T: SigningTranscript,
+        I: IntoIterator<Item=T>,
+    {
+        assert!(self.Rs.len() == public_keys.len(), "{}", ASSERT_MESSAGE);
+
+        let mut transcripts = transcripts.into_iter();
+        let ts: Vec<(T,usize)> = transcripts.by_ref().zip(0..self.Rs.len()).collect();
+        assert!(transcripts.next().is_none(), "{}", ASSERT_MESSAGE);
+        assert!(ts.len() == public_keys.len(), "{}", ASSERT_MESSAGE);
+
+        let hrams: Vec<Scalar> = ts.into_iter().map( |(t,i)|
+            signature_challenge(t, &public_keys[i], &self.Rs[i])
+        ).collect();
+
+        // Replay our merges, recording the parent of each node along
+        // with the weight by which its parent multiplies it.
+        let mut parents: Vec<usize> = Vec::with_capacity(self.shape.len());
+        let mut weights: Vec<Scalar> = Vec::with_capacity(self.shape.len());
+        let mut leaves: Vec<usize> = Vec::with_capacity(self.Rs.len());
+        let mut stack: Vec<(usize,[u8; 32])> = Vec::new();
+        for merge in self.shape.iter() {
+            let k = parents.len();
+            parents.push(k);
+            weights.push(Scalar::one());
+            let c = if *merge {
+                // Our constructors and from_bytes ensure our shape is well formed.
+                let (r, rc) = stack.pop().unwrap();
+                let (l, lc) = stack.pop().unwrap();
+                let (w, c) = half_aggregate_merge(&lc, &rc);
+                parents[l] = k;
+                parents[r] = k;
+                weights[r] = w;
+                c
+            } else {
+                let i = leaves.len();
+                leaves.push(k);
+                half_aggregate_leaf(&public_keys[i], &self.Rs[i], &hrams[i])
+            };
+            stack.push((k,c));
+        }
+        let commitment = stack.last().map(|(_,c)| *c).unwrap_or([0u8; 32]);
+        if commitment != self.commitment {
+            return Err(SignatureError::EquationFalse);
+        }
+
+        // Post-order places parents after their children, so walking
+        // backwards multiplies out the weights along each path.
+        for k in (0..weights.len()).rev() {
+            if parents[k] != k {
+                weights[k] = weights[parents[k]] * weights[k];
+            }
+        }
+        let zs: Vec<Scalar> = leaves.iter().map(|k| weights[*k]).collect();
+
+        verify_batch_equation(
+            self.s,
+            zs, hrams,
+            self.Rs.as_slice(),
+            public_keys, deduplicate_public_keys, false
+        )
+    }
+
+    /// Convert this `HalfAggregateSignature` to bytes, consisting of a
+    /// 4 byte little endian signature count, `s`, our merge commitment,
+    /// the `R`s, and finally our merge tree packed as bits.
+    #[allow(non_snake_case)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HALF_AGGREGATE_HEADER_LENGTH + 32 * self.Rs.len() + packed_bits_len(self.shape.len()));
+        bytes.extend_from_slice(&(self.Rs.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(self.s.as_bytes());
+        bytes.extend_from_slice(&self.commitment);
+        for R in self.Rs.iter() {
+            bytes.extend_from_slice(R.as_bytes());
+        }
+        for bits in self.shape.chunks(8) {
+            bytes.push( bits.iter().enumerate().fold(0u8, |b,(j,bit)| b | ((*bit as u8) << j)) );
+        }
+        bytes
+    }
+
+    /// Construct a `HalfAggregateSignature` from bytes produced by `to_bytes`.
+    #[allow(non_snake_case)]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<HalfAggregateSignature> {
+        use arrayref::array_ref;
+        let length_error = SignatureError::BytesLengthError {
+            name: "HalfAggregateSignature",
+            description: HalfAggregateSignature::DESCRIPTION,
+            length: 0,
+        };
+        if bytes.len() < HALF_AGGREGATE_HEADER_LENGTH {
+            return Err(length_error);
+        }
+        let n = u32::from_le_bytes(*array_ref![bytes,0,4]) as usize;
+        let shape_len = match n.checked_mul(2) {
+            Some(l) => l.saturating_sub(1),
+            None => return Err(length_error),
+        };
+        let expected = n.checked_mul(32)
+            .and_then(|l| l.checked_add(HALF_AGGREGATE_HEADER_LENGTH + packed_bits_len(shape_len)));
+        if expected != Some(bytes.len()) {
+            return Err(length_error);
+        }
+
+        let s = crate::sign::check_scalar(*array_ref![bytes,4,32]) ?;
+        let commitment = *array_ref![bytes,36,32];
+        let (Rs_bytes, shape_bytes) = bytes[HALF_AGGREGATE_HEADER_LENGTH..].split_at(32 * n);
+        let Rs: Vec<CompressedRistretto> = Rs_bytes.chunks(32)
+            .map(|R| CompressedRistretto(*array_ref![R,0,32]))
+            .collect();
+
+        // Unpack our merge tree, and check it merges exactly n signatures.
+        // We report malformed encodings as length errors, like serde does.
+        let shape: Vec<bool> = (0..shape_len)
+            .map(|j| (shape_bytes[j / 8] >> (j % 8)) & 1 == 1)
+            .collect();
+        let padding = shape_len % 8;
+        if padding != 0 && shape_bytes[shape_len / 8] >> padding != 0 {
+            return Err(length_error);
+        }
+        let mut depth = 0usize;
+        let mut leaves = 0usize;
+        for merge in shape.iter() {
+            if *merge {
+                if depth < 2 { return Err(length_error); }
+                depth -= 1;
+            } else {
+                depth += 1;
+                leaves += 1;
+            }
+        }
+        if leaves != n || depth != (n > 0) as usize {
+            return Err(length_error);
+        }
+        if n == 0 && (s != Scalar::zero() || commitment != [0u8; 32]) {
+            return Err(length_error);
+        }
+
+        Ok(HalfAggregateSignature { s, Rs, shape, commitment })
+    }
+}
+
+serde_boilerplate!(HalfAggregateSignature);
+
+
 pub fn reserve_mut<'heap, T>(heap: &mut &'heap mut [T], len: usize) -> &'heap mut [T] {
     let tmp: &'heap mut [T] = ::std::mem::replace(&mut *heap, &mut []);
     let (reserved, tmp) = tmp.split_at_mut(len);
@@ -409,6 +1086,8 @@ mod test {
 
     use rand::prelude::*; // ThreadRng,thread_rng
 
+    use curve25519_dalek::ristretto::CompressedRistretto;
+
     use super::super::*;
 
     #[cfg(any(feature = "alloc", feature = "std"))]
@@ -454,4 +1133,144 @@ mod test {
         let transcripts = messages.iter().map(|m| ctx.bytes(m));
         assert!( verify_batch(transcripts, &signatures[..], &public_keys[..], true).is_err() );
     }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn verify_batch_identify_bad_signatures() {
+        let ctx = signing_context(b"my batch context");
+        let mut csprng: ThreadRng = thread_rng();
+
+        let messages: Vec<[u8; 8]> = (0..37u64).map(|i| i.to_le_bytes()).collect();
+        let keypairs: Vec<Keypair> = (0..messages.len()).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let mut signatures: Vec<Signature> = keypairs.iter().zip(messages.iter())
+            .map(|(key,m)| key.sign(ctx.bytes(m))).collect();
+        let public_keys: Vec<PublicKey> = keypairs.iter().map(|key| key.public).collect();
+
+        let transcripts = messages.iter().map(|m| ctx.bytes(m));
+        assert!( verify_batch_identify(transcripts, &signatures[..], &public_keys[..], false).is_empty() );
+
+        signatures.swap(3,4);
+        signatures[20] = keypairs[20].sign(ctx.bytes(b"some other message"));
+        signatures[36].R = CompressedRistretto([255u8; 32]);
+        for dedup in [false, true].iter() {
+            let transcripts = messages.iter().map(|m| ctx.bytes(m));
+            assert_eq!( verify_batch_identify(transcripts, &signatures[..], &public_keys[..], *dedup),
+                        vec![3, 4, 20, 36] );
+        }
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn batch_verifier_signatures_and_vrfs() {
+        let ctx = signing_context(b"my batch context");
+        let mut csprng: ThreadRng = thread_rng();
+        let keypairs: Vec<Keypair> = (0..8).map(|_| Keypair::generate_with(&mut csprng)).collect();
+
+        let signatures: Vec<Signature> = keypairs.iter().map(|k| k.sign(ctx.bytes(b"sign"))).collect();
+        let vrfs: Vec<_> = keypairs.iter().map(|k| k.vrf_sign(ctx.bytes(b"vrf"))).collect();
+
+        let build = |signatures: &[Signature], ios: &[crate::vrf::VRFInOut]| {
+            let mut batch = BatchVerifier::new();
+            for (i, k) in keypairs.iter().enumerate() {
+                batch.push_signature(ctx.bytes(b"sign"), &signatures[i], &k.public);
+                batch.push_vrf(&ios[i], &vrfs[i].2, &k.public);
+            }
+            assert_eq!( batch.len(), 2 * keypairs.len() );
+            batch
+        };
+        let mut ios: Vec<_> = vrfs.iter().map(|v| v.0.clone()).collect();
+        assert!( build(&signatures, &ios).finalize(thread_rng()).is_ok(),
+            "Batch verification of valid signatures and VRF proofs failed!" );
+
+        let mut bad_signatures = signatures.clone();
+        bad_signatures.swap(0, 1);
+        assert!( build(&bad_signatures, &ios).finalize(thread_rng()).is_err(),
+            "Batch verification of signatures by the wrong keys passed!" );
+
+        ios.swap(2, 3);
+        assert!( build(&signatures, &ios).finalize(thread_rng()).is_err(),
+            "Batch verification of VRF outputs by the wrong keys passed!" );
+    }
+
+    #[test]
+    fn half_aggregate_push_merge_encode() {
+        let ctx = signing_context(b"my block context");
+        let mut csprng: ThreadRng = thread_rng();
+
+        let messages: Vec<[u8; 8]> = (0..9u64).map(|i| i.to_le_bytes()).collect();
+        let keypairs: Vec<Keypair> = (0..messages.len()).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let signatures: Vec<Signature> = keypairs.iter().zip(messages.iter())
+            .map(|(k,m)| k.sign(ctx.bytes(m))).collect();
+        let public_keys: Vec<PublicKey> = keypairs.iter().map(|k| k.public).collect();
+        let aggregate = |range: core::ops::Range<usize>| {
+            let mut agg = HalfAggregateSignature::new();
+            for ((m, sig), pk) in messages[range.clone()].iter().zip(&signatures[range.clone()]).zip(&public_keys[range]) {
+                agg.push(ctx.bytes(m), sig, pk);
+            }
+            agg
+        };
+        let transcripts = || messages.iter().map(|m| ctx.bytes(m));
+
+        let mut agg = aggregate(0..2);
+        let mut right = aggregate(5..7);
+        right.merge(aggregate(7..9));
+        agg.merge(aggregate(2..5));
+        agg.merge(right);
+        assert_eq!(agg.len(), messages.len());
+        assert!( agg.verify(transcripts(), &public_keys[..], false).is_ok(),
+            "Verification of a valid half-aggregated signature failed!" );
+        assert!( agg.verify(transcripts(), &public_keys[..], true).is_ok(),
+            "Verification of a valid half-aggregated signature with deduplication failed!" );
+
+        let decoded = HalfAggregateSignature::from_bytes(&agg.to_bytes()[..]).unwrap();
+        assert_eq!(decoded, agg);
+        let empty = HalfAggregateSignature::new();
+        assert_eq!(HalfAggregateSignature::from_bytes(&empty.to_bytes()[..]).unwrap(), empty);
+        assert!( HalfAggregateSignature::from_bytes(&agg.to_bytes()[1..]).is_err() );
+
+        // Merging the same signatures in a different order changes the weights.
+        let mut reordered = aggregate(0..5);
+        reordered.merge(aggregate(5..9));
+        assert!( reordered.verify(transcripts(), &public_keys[..], false).is_ok() );
+        assert_ne!(reordered, agg);
+
+        let mut swapped = public_keys.clone();
+        swapped.swap(3, 4);
+        assert!( agg.verify(transcripts(), &swapped[..], false).is_err(),
+            "Verification of a half-aggregated signature with swapped keys passed!" );
+        let mut bad = aggregate(0..8);
+        bad.push(ctx.bytes(b"something else"), &signatures[8], &public_keys[8]);
+        assert!( bad.verify(transcripts(), &public_keys[..], false).is_err(),
+            "Verification of a half-aggregated signature on a wrong message passed!" );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn verify_batch_parallel_matches_single_threaded() {
+        use super::{prepare_batch,prepare_batch_parallel,NotAnRng};
+
+        let ctx = signing_context(b"my batch context");
+        let mut csprng: ThreadRng = thread_rng();
+
+        let messages: Vec<[u8; 8]> = (0..300u64).map(|i| i.to_le_bytes()).collect();
+        let keypairs: Vec<Keypair> = (0..messages.len()).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let mut signatures: Vec<Signature> = keypairs.iter().zip(messages.iter())
+            .map(|(key,m)| key.sign(ctx.bytes(m))).collect();
+        let public_keys: Vec<PublicKey> = keypairs.iter().map(|key| key.public).collect();
+        let transcripts = || messages.iter().map(|m| ctx.bytes(m));
+
+        let serial = prepare_batch(transcripts(), &signatures[..], &public_keys[..], NotAnRng);
+        let parallel = prepare_batch_parallel(transcripts(), &signatures[..], &public_keys[..], NotAnRng);
+        assert!( serial == parallel, "Parallel batch preparation differs from single-threaded!" );
+
+        for dedup in [false, true].iter() {
+            assert!( verify_batch(transcripts(), &signatures[..], &public_keys[..], *dedup).is_ok() );
+            assert!( verify_batch_parallel(transcripts(), &signatures[..], &public_keys[..], *dedup).is_ok() );
+        }
+        signatures.swap(150, 299);
+        for dedup in [false, true].iter() {
+            assert!( verify_batch(transcripts(), &signatures[..], &public_keys[..], *dedup).is_err() );
+            assert!( verify_batch_parallel(transcripts(), &signatures[..], &public_keys[..], *dedup).is_err() );
+        }
+    }
 }
@@ -0,0 +1,335 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Blind Schnorr signature issuance resistant to ROS attacks
+//!
+//! Plain blind Schnorr signatures fall to the concurrent session
+//! attacks of "On the (in)security of ROS" by Fabrice Benhamouda,
+//! Tancrède Lepoint, Julian Loss, Michele Orrù, and Mariana Raykova
+//! https://eprint.iacr.org/2020/945 which forge one more signature
+//! than the issuer signed in polynomial time.
+//!
+//! We therefore implement the clause blind Schnorr signatures from
+//! "Concurrently Secure Blind Schnorr Signatures" by Georg Fuchsbauer
+//! and Mathias Wolf https://eprint.iacr.org/2022/1676 in which the
+//! issuer commits to two nonces, the user blinds a challenge for
+//! each, and the issuer answers only one challenge, chosen at random.
+//! An attacker must then solve the harder modified ROS problem.
+//!
+//! Any issuance yields an ordinary `Signature` under the issuer's
+//! `PublicKey`, verifiable with `PublicKey::verify` using the user's
+//! `SigningTranscript`, which the issuer never sees.
+//!
+//! We model the protocol with stage types, as in musig.rs, so that
+//! each issuer session answers exactly one challenge.  We derive the
+//! issuer's nonces and clause choice from its own `SigningTranscript`
+//! via `witness_scalar`, so issuers must never reuse a session
+//! transcript with a broken random number generator.
+
+use core::borrow::{Borrow};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::points::RistrettoBoth;
+
+
+/// Length of the issuer's `BlindCommitment`, in bytes.
+pub const BLIND_COMMITMENT_LENGTH: usize = 64;
+
+/// Length of the user's `BlindChallenge`, in bytes.
+pub const BLIND_CHALLENGE_LENGTH: usize = 64;
+
+/// Length of the issuer's `BlindResponse`, in bytes.
+pub const BLIND_RESPONSE_LENGTH: usize = 32;
+
+
+/// Issuer's two nonce commitments `R_0` and `R_1` sent to the user
+#[allow(non_snake_case)]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct BlindCommitment {
+    Rs: [CompressedRistretto; 2],
+}
+
+impl BlindCommitment {
+    const DESCRIPTION : &'static str = "Two Ristretto points committing to blind signature nonces, making it 64 bytes";
+
+    /// Convert this `BlindCommitment` to a byte array.
+    pub fn to_bytes(&self) -> [u8; BLIND_COMMITMENT_LENGTH] {
+        let mut bytes = [0u8; BLIND_COMMITMENT_LENGTH];
+        bytes[..32].copy_from_slice(self.Rs[0].as_bytes());
+        bytes[32..].copy_from_slice(self.Rs[1].as_bytes());
+        bytes
+    }
+
+    /// Construct a `BlindCommitment` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<BlindCommitment> {
+        if bytes.len() != BLIND_COMMITMENT_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "BlindCommitment",
+                description: BlindCommitment::DESCRIPTION,
+                length: BLIND_COMMITMENT_LENGTH
+            });
+        }
+        let mut r0 = CompressedRistretto([0u8; 32]);
+        let mut r1 = CompressedRistretto([0u8; 32]);
+        r0.0.copy_from_slice(&bytes[..32]);
+        r1.0.copy_from_slice(&bytes[32..]);
+        Ok(BlindCommitment { Rs: [r0, r1] })
+    }
+}
+
+serde_boilerplate!(BlindCommitment);
+
+/// User's two blinded challenges `c_0` and `c_1` sent to the issuer
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct BlindChallenge {
+    cs: [Scalar; 2],
+}
+
+impl BlindChallenge {
+    const DESCRIPTION : &'static str = "Two scalars giving blinded signature challenges, making it 64 bytes";
+
+    /// Convert this `BlindChallenge` to a byte array.
+    pub fn to_bytes(&self) -> [u8; BLIND_CHALLENGE_LENGTH] {
+        let mut bytes = [0u8; BLIND_CHALLENGE_LENGTH];
+        bytes[..32].copy_from_slice(self.cs[0].as_bytes());
+        bytes[32..].copy_from_slice(self.cs[1].as_bytes());
+        bytes
+    }
+
+    /// Construct a `BlindChallenge` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<BlindChallenge> {
+        if bytes.len() != BLIND_CHALLENGE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "BlindChallenge",
+                description: BlindChallenge::DESCRIPTION,
+                length: BLIND_CHALLENGE_LENGTH
+            });
+        }
+        let mut c0 = [0u8; 32];
+        let mut c1 = [0u8; 32];
+        c0.copy_from_slice(&bytes[..32]);
+        c1.copy_from_slice(&bytes[32..]);
+        let c0 = Scalar::from_canonical_bytes(c0).ok_or(SignatureError::ScalarFormatError) ?;
+        let c1 = Scalar::from_canonical_bytes(c1).ok_or(SignatureError::ScalarFormatError) ?;
+        Ok(BlindChallenge { cs: [c0, c1] })
+    }
+}
+
+serde_boilerplate!(BlindChallenge);
+
+/// Issuer's response `s = r_b + c_b a` for its randomly chosen clause `b`
+///
+/// We encode the clause `b` in the high bit of the scalar, much like
+/// `Signature` uses the high bit to mark schnorrkel signatures.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct BlindResponse {
+    b: usize,
+    s: Scalar,
+}
+
+impl BlindResponse {
+    const DESCRIPTION : &'static str = "A scalar with its high bit selecting the answered blind signature clause, making it 32 bytes";
+
+    /// Convert this `BlindResponse` to a byte array.
+    pub fn to_bytes(&self) -> [u8; BLIND_RESPONSE_LENGTH] {
+        let mut bytes = self.s.to_bytes();
+        bytes[31] |= (self.b as u8) << 7;
+        bytes
+    }
+
+    /// Construct a `BlindResponse` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<BlindResponse> {
+        if bytes.len() != BLIND_RESPONSE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "BlindResponse",
+                description: BlindResponse::DESCRIPTION,
+                length: BLIND_RESPONSE_LENGTH
+            });
+        }
+        let mut s = [0u8; 32];
+        s.copy_from_slice(bytes);
+        let b = (s[31] >> 7) as usize;
+        s[31] &= 127;
+        Ok(BlindResponse { b, s: crate::sign::check_scalar(s) ? })
+    }
+}
+
+serde_boilerplate!(BlindResponse);
+
+
+impl Keypair {
+    /// Initialize a blind signature issuance session.
+    ///
+    /// We borrow the keypair here to discurage keeping too many
+    /// copies of the private key, but the `IssuerCommitStage::new`
+    /// method can create an owned version, or use `Rc` or `Arc`.
+    pub fn blind_issue<T>(&self, t: T) -> IssuerCommitStage<&Keypair>
+    where T: SigningTranscript {
+        IssuerCommitStage::new(self,t)
+    }
+}
+
+/// Issuer's stage after committing to its two nonces.
+///
+/// We consume this stage when answering the user's challenge, so
+/// each session answers only one challenge.
+#[allow(non_snake_case)]
+pub struct IssuerCommitStage<K: Borrow<Keypair>> {
+    keypair: K,
+    r_me: [Scalar; 2],
+    b: usize,
+    commitment: BlindCommitment,
+}
+
+impl<K: Borrow<Keypair>> IssuerCommitStage<K> {
+    /// Initialize a blind signature issuance session.
+    ///
+    /// Issuers should supply a transcript `t` unique to this session,
+    /// so that nonces remain independent even if their random number
+    /// generator fails.
+    #[allow(non_snake_case)]
+    pub fn new<T: SigningTranscript>(keypair: K, mut t: T) -> IssuerCommitStage<K> {
+        t.proto_name(b"ClauseBlindSchnorr");
+        t.commit_point(b"issuer-pk",keypair.borrow().public.as_compressed());
+
+        let nonce = &keypair.borrow().secret.nonce;
+        let r_me = [
+            t.witness_scalar(b"blind-nonce",&[nonce,&[0u8]]),
+            t.witness_scalar(b"blind-nonce",&[nonce,&[1u8]]),
+        ];
+        let mut b = [0u8; 1];
+        t.witness_bytes(b"blind-clause",&mut b,&[nonce]);
+
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        let Rs = [ (&r_me[0] * B).compress(), (&r_me[1] * B).compress() ];
+        IssuerCommitStage { keypair, r_me, b: (b[0] & 1) as usize, commitment: BlindCommitment { Rs } }
+    }
+
+    /// Our commitment to send to the user
+    pub fn our_commitment(&self) -> BlindCommitment { self.commitment }
+
+    /// Answer the user's blinded challenge for our randomly chosen clause.
+    pub fn respond(mut self, challenge: &BlindChallenge) -> BlindResponse {
+        let b = self.b;
+        let s = self.r_me[b] + challenge.cs[b] * self.keypair.borrow().secret.key;
+        zeroize::Zeroize::zeroize(&mut self.r_me);
+        BlindResponse { b, s }
+    }
+}
+
+/// User's stage after blinding both challenges.
+#[allow(non_snake_case)]
+pub struct UserChallengeStage {
+    issuer: PublicKey,
+    commitment: BlindCommitment,
+    alphas: [Scalar; 2],
+    Rs: [CompressedRistretto; 2],
+    challenge: BlindChallenge,
+}
+
+impl PublicKey {
+    /// Blind the message transcript `t` for signing by the issuer
+    /// with this public key, given the issuer's commitment.
+    ///
+    /// We compute the final `Signature` challenges using the same
+    /// transcript layout as `SecretKey::sign`, so the unblinded
+    /// `Signature` verifies under `PublicKey::verify` with `t`.
+    #[allow(non_snake_case)]
+    pub fn blind_challenge<T>(&self, t: T, commitment: &BlindCommitment)
+     -> SignatureResult<UserChallengeStage>
+    where T: SigningTranscript+Clone
+    {
+        let A: &RistrettoPoint = self.as_point();
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+
+        let mut alphas = [Scalar::zero(); 2];
+        let mut Rs = [CompressedRistretto::default(); 2];
+        let mut cs = [Scalar::zero(); 2];
+        for i in 0..2 {
+            let R_i = RistrettoBoth::from_compressed(commitment.Rs[i]) ?;
+            let alpha = t.witness_scalar(b"blind-alpha",&[&[i as u8]]);
+            let beta = t.witness_scalar(b"blind-beta",&[&[i as u8]]);
+            let R = (R_i.as_point() + &alpha * B + beta * A).compress();
+
+            let mut t0 = t.clone();
+            t0.proto_name(b"Schnorr-sig");
+            t0.commit_point(b"sign:pk",self.as_compressed());
+            t0.commit_point(b"sign:R",&R);
+            let c = t0.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
+
+            alphas[i] = alpha;
+            Rs[i] = R;
+            cs[i] = c + beta;
+        }
+        let challenge = BlindChallenge { cs };
+        Ok(UserChallengeStage { issuer: *self, commitment: *commitment, alphas, Rs, challenge })
+    }
+}
+
+impl UserChallengeStage {
+    /// Our blinded challenges to send to the issuer
+    pub fn our_challenge(&self) -> BlindChallenge { self.challenge }
+
+    /// Check the issuer's response and unblind it into a `Signature`.
+    #[allow(non_snake_case)]
+    pub fn unblind(mut self, response: &BlindResponse) -> SignatureResult<Signature> {
+        let b = response.b;
+        let R_b = RistrettoBoth::from_compressed(self.commitment.Rs[b]) ?;
+        let A: &RistrettoPoint = self.issuer.as_point();
+
+        // Check s B = R_b + c_b A before unblinding
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&self.challenge.cs[b], &(-A), &response.s);
+        if R != *R_b.as_point() {
+            return Err(SignatureError::EquationFalse);
+        }
+
+        let s = response.s + self.alphas[b];
+        zeroize::Zeroize::zeroize(&mut self.alphas);
+        Ok(Signature { R: self.Rs[b], s })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blind_issue_unblind_verify() {
+        let issuer = Keypair::generate();
+        let t = signing_context(b"anonymous tokens").bytes(b"token serial 42");
+
+        let issuing = issuer.blind_issue(signing_context(b"session").bytes(b"1"));
+        let commitment = BlindCommitment::from_bytes(&issuing.our_commitment().to_bytes()).unwrap();
+
+        let user = issuer.public.blind_challenge(t.clone(), &commitment).unwrap();
+        let challenge = BlindChallenge::from_bytes(&user.our_challenge().to_bytes()).unwrap();
+
+        let response = issuing.respond(&challenge);
+        let response = BlindResponse::from_bytes(&response.to_bytes()).unwrap();
+
+        let signature = user.unblind(&response).unwrap();
+        assert!( issuer.public.verify(t.clone(), &signature).is_ok(),
+            "Verification of an unblinded signature failed!" );
+        assert!( issuer.public.verify(signing_context(b"anonymous tokens").bytes(b"token serial 43"), &signature).is_err(),
+            "Verification of an unblinded signature on a different message passed!" );
+
+        // The issuer's view never contains the final R or s.
+        assert!( signature.R != commitment.Rs[0] && signature.R != commitment.Rs[1] );
+        assert!( signature.s != response.s );
+
+        // A response from another session fails to unblind.
+        let other = issuer.blind_issue(signing_context(b"session").bytes(b"2"));
+        let user = issuer.public.blind_challenge(t, &commitment).unwrap();
+        let bad = other.respond(&user.our_challenge());
+        assert!( user.unblind(&bad).is_err() );
+    }
+}
@@ -21,6 +21,8 @@ use curve25519_dalek::digest::generic_array::typenum::{U32,U64};
 use curve25519_dalek::ristretto::CompressedRistretto; // RistrettoPoint
 use curve25519_dalek::scalar::Scalar;
 
+use sha2::Sha512;
+
 
 // === Signing context as transcript === //
 
@@ -244,6 +246,52 @@ impl SigningContext {
         t.append_message(b"sign-512", &prehash);
         t
     }
+
+    /// Initialize a `SigningStream` into which callers feed a message
+    /// incrementally, for messages too large to hold in memory.
+    ///
+    /// We prehash with SHA-512 and then commit exactly like `hash512`
+    /// does, so `SigningStream::finalize` equals
+    /// `self.hash512(Sha512::default().chain(message))`.
+    #[inline(always)]
+    pub fn streaming(&self) -> SigningStream {
+        SigningStream { context: self.clone(), h: Sha512::default() }
+    }
+}
+
+
+/// Streaming SHA-512 prehash of a message under some `SigningContext`.
+///
+/// We implement `std::io::Write` so `std::io::copy` can feed us from
+/// any reader, but chunk boundaries never influence the prehash.
+#[derive(Clone)] // Debug
+pub struct SigningStream {
+    context: SigningContext,
+    h: Sha512,
+}
+
+impl SigningStream {
+    /// Absorb the next chunk of the message.
+    #[inline(always)]
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.h.update(chunk);
+    }
+
+    /// Finalize the prehash into an owned signing transcript.
+    #[inline(always)]
+    pub fn finalize(self) -> Transcript {
+        self.context.hash512(self.h)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for SigningStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
 }
 
 
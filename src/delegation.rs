@@ -0,0 +1,355 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Delegation warrants and proxy signatures
+//!
+//! A long-term delegator key signs a `Warrant` naming a delegate
+//! `PublicKey`, the signing contexts the delegate may sign in, and a
+//! validity window.  The delegate then produces `ProxySignature`s,
+//! which verify as signed by the delegate on behalf of the delegator.
+//! We thus support session keys authorised by cold stash keys.
+//!
+//! We measure validity windows in whatever units callers choose,
+//! like block numbers or unix time, and treat both ends as inclusive.
+//!
+//! We sign warrants on their own transcript, and bind each proxy
+//! signature to its warrant by committing the delegator and warrant
+//! signature into the delegate's transcript before signing, so that
+//! neither warrants nor proxy signatures double as ordinary signatures,
+//! and proxy signatures never transfer between warrants.
+
+use arrayref::array_ref;
+use merlin::Transcript;
+
+use super::*;
+use crate::context::{SigningTranscript,SigningContext};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+
+/// A delegator's signed authorisation for a delegate key to sign
+/// in the listed contexts during the validity window.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warrant {
+    /// Long-term key authorising the delegate
+    delegator: PublicKey,
+    /// Key authorised to sign on behalf of the delegator
+    delegate: PublicKey,
+    /// Signing contexts in which the delegate may sign
+    contexts: Vec<Vec<u8>>,
+    /// First moment of validity, inclusive
+    valid_from: u64,
+    /// Last moment of validity, inclusive
+    valid_until: u64,
+    /// Delegator's signature on all the above
+    signature: Signature,
+}
+
+/// Transcript on which delegators sign warrants.
+fn warrant_transcript(
+    delegator: &PublicKey,
+    delegate: &PublicKey,
+    contexts: &[Vec<u8>],
+    valid_from: u64,
+    valid_until: u64,
+) -> Transcript {
+    let mut t = Transcript::new(b"SchnorrkelWarrant");
+    t.commit_point(b"warrant:delegator", delegator.as_compressed());
+    t.commit_point(b"warrant:delegate", delegate.as_compressed());
+    t.append_u64(b"warrant:contexts", contexts.len() as u64);
+    for ctx in contexts {
+        t.append_message(b"warrant:context", ctx);
+    }
+    t.append_u64(b"warrant:valid-from", valid_from);
+    t.append_u64(b"warrant:valid-until", valid_until);
+    t
+}
+
+impl Warrant {
+    const DESCRIPTION : &'static str = "A delegation warrant";
+
+    /// Long-term key authorising the delegate
+    pub fn delegator(&self) -> &PublicKey { &self.delegator }
+
+    /// Key authorised to sign on behalf of the delegator
+    pub fn delegate(&self) -> &PublicKey { &self.delegate }
+
+    /// Signing contexts in which the delegate may sign
+    pub fn contexts(&self) -> &[Vec<u8>] { &self.contexts }
+
+    /// Validity window, with both ends inclusive
+    pub fn validity(&self) -> (u64,u64) { (self.valid_from, self.valid_until) }
+
+    /// Returns true if this warrant authorises signing in `ctx` at `now`,
+    /// without checking the delegator's signature.
+    pub fn permits(&self, ctx: &[u8], now: u64) -> bool {
+        self.valid_from <= now && now <= self.valid_until
+        && self.contexts.iter().any(|c| &c[..] == ctx)
+    }
+
+    fn transcript(&self) -> Transcript {
+        warrant_transcript(&self.delegator, &self.delegate, &self.contexts, self.valid_from, self.valid_until)
+    }
+
+    /// Verify the delegator's signature on this warrant.
+    pub fn verify(&self) -> SignatureResult<()> {
+        self.delegator.verify(self.transcript(), &self.signature)
+    }
+
+    /// Transcript for a proxy signature under this warrant on `msg` in `ctx`.
+    fn proxy_transcript(&self, ctx: &[u8], msg: &[u8]) -> Transcript {
+        let mut t = SigningContext::new(ctx).bytes(msg);
+        t.commit_point(b"proxy:delegator", self.delegator.as_compressed());
+        t.append_message(b"proxy:warrant", &self.signature.to_bytes());
+        t
+    }
+
+    /// Number of bytes in our serialization
+    fn byte_len(&self) -> usize {
+        32 + 32 + 8 + 8 + 4 + self.contexts.iter().map(|c| 4 + c.len()).sum::<usize>() + SIGNATURE_LENGTH
+    }
+
+    /// Convert this `Warrant` to bytes, consisting of the delegator,
+    /// delegate, validity window as little endian `u64`s, a 4 byte
+    /// little endian context count, each context prefixed by its 4 byte
+    /// little endian length, and finally the delegator's signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_len());
+        bytes.extend_from_slice(self.delegator.as_compressed().as_bytes());
+        bytes.extend_from_slice(self.delegate.as_compressed().as_bytes());
+        bytes.extend_from_slice(&self.valid_from.to_le_bytes());
+        bytes.extend_from_slice(&self.valid_until.to_le_bytes());
+        bytes.extend_from_slice(&(self.contexts.len() as u32).to_le_bytes());
+        for ctx in self.contexts.iter() {
+            bytes.extend_from_slice(&(ctx.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(ctx);
+        }
+        bytes.extend_from_slice(&self.signature.to_bytes());
+        bytes
+    }
+
+    /// Construct a `Warrant` from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<Warrant> {
+        let (warrant, rest) = Warrant::read_bytes(bytes) ?;
+        if ! rest.is_empty() {
+            return Err(Warrant::length_error());
+        }
+        Ok(warrant)
+    }
+
+    fn length_error() -> SignatureError {
+        SignatureError::BytesLengthError {
+            name: "Warrant",
+            description: Warrant::DESCRIPTION,
+            length: 0,
+        }
+    }
+
+    /// Remove `len` bytes from the front of `bytes`.
+    fn take<'a>(bytes: &mut &'a [u8], len: usize) -> SignatureResult<&'a [u8]> {
+        if bytes.len() < len { return Err(Warrant::length_error()); }
+        let (head,tail) = bytes.split_at(len);
+        *bytes = tail;
+        Ok(head)
+    }
+
+    /// Read a `Warrant` from the front of `bytes`, returning any bytes left over.
+    fn read_bytes(mut bytes: &[u8]) -> SignatureResult<(Warrant,&[u8])> {
+        let read = Warrant::take;
+        let delegator = PublicKey::from_bytes(read(&mut bytes, 32) ?) ?;
+        let delegate = PublicKey::from_bytes(read(&mut bytes, 32) ?) ?;
+        let valid_from = u64::from_le_bytes(*array_ref![read(&mut bytes, 8) ?,0,8]);
+        let valid_until = u64::from_le_bytes(*array_ref![read(&mut bytes, 8) ?,0,8]);
+        let n = u32::from_le_bytes(*array_ref![read(&mut bytes, 4) ?,0,4]) as usize;
+        // Each context requires at least 4 bytes, so we never allocate
+        // more than the input length here.
+        let mut contexts = Vec::with_capacity(n.min(bytes.len() / 4));
+        for _ in 0..n {
+            let len = u32::from_le_bytes(*array_ref![read(&mut bytes, 4) ?,0,4]) as usize;
+            contexts.push(read(&mut bytes, len) ?.to_vec());
+        }
+        let signature = Signature::from_bytes(read(&mut bytes, SIGNATURE_LENGTH) ?) ?;
+        Ok((Warrant { delegator, delegate, contexts, valid_from, valid_until, signature }, bytes))
+    }
+}
+
+serde_boilerplate!(Warrant);
+
+
+/// A signature by a delegate on behalf of the delegator who issued its `Warrant`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxySignature {
+    /// Warrant authorising the delegate
+    warrant: Warrant,
+    /// Delegate's signature
+    signature: Signature,
+}
+
+impl ProxySignature {
+    const DESCRIPTION : &'static str = "A delegation warrant followed by a proxy signature";
+
+    /// Warrant authorising the delegate
+    pub fn warrant(&self) -> &Warrant { &self.warrant }
+
+    /// Verify this proxy signature on `msg` in `ctx` at time `now`,
+    /// as signed on behalf of `delegator`.
+    ///
+    /// We return `WarrantNotApplicable` if the warrant does not cover
+    /// `ctx` or `now`, and `EquationFalse` if it was issued by another
+    /// delegator, or for any invalid signature.
+    pub fn verify(&self, delegator: &PublicKey, ctx: &[u8], msg: &[u8], now: u64) -> SignatureResult<()> {
+        if self.warrant.delegator != *delegator {
+            return Err(SignatureError::EquationFalse);
+        }
+        if ! self.warrant.permits(ctx, now) {
+            return Err(SignatureError::WarrantNotApplicable);
+        }
+        self.warrant.verify() ?;
+        self.warrant.delegate.verify(self.warrant.proxy_transcript(ctx, msg), &self.signature)
+    }
+
+    /// Convert this `ProxySignature` to bytes, consisting of its
+    /// warrant's bytes followed by the delegate's signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.warrant.to_bytes();
+        bytes.extend_from_slice(&self.signature.to_bytes());
+        bytes
+    }
+
+    /// Construct a `ProxySignature` from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<ProxySignature> {
+        let (warrant, rest) = Warrant::read_bytes(bytes) ?;
+        if rest.len() != SIGNATURE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "ProxySignature",
+                description: ProxySignature::DESCRIPTION,
+                length: 0,
+            });
+        }
+        let signature = Signature::from_bytes(rest) ?;
+        Ok(ProxySignature { warrant, signature })
+    }
+}
+
+serde_boilerplate!(ProxySignature);
+
+
+impl Keypair {
+    /// Issue a `Warrant` authorising `delegate` to sign on our behalf
+    /// in the given contexts from `valid_from` until `valid_until` inclusive.
+    pub fn issue_warrant(&self, delegate: &PublicKey, contexts: &[&[u8]], valid_from: u64, valid_until: u64) -> Warrant {
+        let contexts: Vec<Vec<u8>> = contexts.iter().map(|c| c.to_vec()).collect();
+        let t = warrant_transcript(&self.public, delegate, &contexts, valid_from, valid_until);
+        let signature = self.sign(t);
+        Warrant { delegator: self.public, delegate: *delegate, contexts, valid_from, valid_until, signature }
+    }
+
+    /// Sign `msg` in `ctx` on behalf of the delegator who issued `warrant`.
+    ///
+    /// We return `WarrantNotApplicable` if `warrant` does not authorise
+    /// `ctx`, and `EquationFalse` if `warrant` names another delegate.
+    /// We do not check the validity window, since signers may sign
+    /// ahead of time.
+    pub fn sign_on_behalf(&self, warrant: &Warrant, ctx: &[u8], msg: &[u8]) -> SignatureResult<ProxySignature> {
+        if warrant.delegate != self.public {
+            return Err(SignatureError::EquationFalse);
+        }
+        if ! warrant.contexts.iter().any(|c| &c[..] == ctx) {
+            return Err(SignatureError::WarrantNotApplicable);
+        }
+        let signature = self.sign(warrant.proxy_transcript(ctx, msg));
+        Ok(ProxySignature { warrant: warrant.clone(), signature })
+    }
+}
+
+
+/// Verify a batch of proxy signatures at time `now`, with each signed
+/// on behalf of the corresponding delegator on the corresponding
+/// message in the corresponding context.
+///
+/// We check warrants' delegators, contexts, and validity windows first,
+/// and then batch verify both warrant and proxy signatures together,
+/// deduplicating delegators' public keys.
+pub fn verify_proxy_batch(
+    proxies: &[ProxySignature],
+    delegators: &[PublicKey],
+    contexts: &[&[u8]],
+    messages: &[&[u8]],
+    now: u64,
+) -> SignatureResult<()>
+{
+    const ASSERT_MESSAGE: &str = "The number of proxy signatures, delegators, contexts, and messages must be equal.";
+    assert!(proxies.len() == delegators.len(), "{}", ASSERT_MESSAGE);
+    assert!(proxies.len() == contexts.len(), "{}", ASSERT_MESSAGE);
+    assert!(proxies.len() == messages.len(), "{}", ASSERT_MESSAGE);
+
+    let mut transcripts = Vec::with_capacity(2 * proxies.len());
+    let mut signatures = Vec::with_capacity(2 * proxies.len());
+    let mut public_keys = Vec::with_capacity(2 * proxies.len());
+    for (((proxy, delegator), ctx), msg) in proxies.iter().zip(delegators).zip(contexts).zip(messages) {
+        let warrant = &proxy.warrant;
+        if warrant.delegator != *delegator {
+            return Err(SignatureError::EquationFalse);
+        }
+        if ! warrant.permits(ctx, now) {
+            return Err(SignatureError::WarrantNotApplicable);
+        }
+        transcripts.push(warrant.transcript());
+        signatures.push(warrant.signature);
+        public_keys.push(warrant.delegator);
+        transcripts.push(warrant.proxy_transcript(ctx, msg));
+        signatures.push(proxy.signature);
+        public_keys.push(warrant.delegate);
+    }
+    verify_batch(transcripts, &signatures, &public_keys, true)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warrant_proxy_sign_verify_batch() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let stash = Keypair::generate_with(&mut csprng);
+        let session = Keypair::generate_with(&mut csprng);
+        let other = Keypair::generate_with(&mut csprng);
+
+        let warrant = stash.issue_warrant(&session.public, &[b"babe", b"grandpa"], 100, 200);
+        assert!(warrant.verify().is_ok());
+        assert_eq!(Warrant::from_bytes(&warrant.to_bytes()[..]).unwrap(), warrant);
+
+        let proxy = session.sign_on_behalf(&warrant, b"babe", b"block 150").unwrap();
+        let proxy = ProxySignature::from_bytes(&proxy.to_bytes()[..]).unwrap();
+        assert!( proxy.verify(&stash.public, b"babe", b"block 150", 150).is_ok(),
+            "Verification of a valid proxy signature failed!" );
+        assert!( proxy.verify(&stash.public, b"babe", b"block 151", 150).is_err(),
+            "Verification of a proxy signature on a different message passed!" );
+        assert!( proxy.verify(&other.public, b"babe", b"block 150", 150).is_err(),
+            "Verification of a proxy signature for another delegator passed!" );
+        assert_eq!( proxy.verify(&stash.public, b"babe", b"block 150", 201),
+            Err(SignatureError::WarrantNotApplicable) );
+        assert_eq!( session.sign_on_behalf(&warrant, b"imonline", b"hi").unwrap_err(),
+            SignatureError::WarrantNotApplicable );
+        assert!( other.sign_on_behalf(&warrant, b"babe", b"block 150").is_err() );
+        assert!( stash.public.verify_simple(b"babe", b"block 150", &proxy.signature).is_err() );
+        assert!( session.public.verify_simple(b"babe", b"block 150", &proxy.signature).is_err() );
+
+        let proxy2 = session.sign_on_behalf(&warrant, b"grandpa", b"vote").unwrap();
+        let proxies = [proxy.clone(), proxy2];
+        let delegators = [stash.public, stash.public];
+        assert!( verify_proxy_batch(&proxies, &delegators, &[b"babe", b"grandpa"], &[b"block 150", b"vote"], 120).is_ok(),
+            "Batch verification of valid proxy signatures failed!" );
+        assert!( verify_proxy_batch(&proxies, &delegators, &[b"babe", b"grandpa"], &[b"vote", b"block 150"], 120).is_err(),
+            "Batch verification of proxy signatures on swapped messages passed!" );
+    }
+}
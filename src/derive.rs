@@ -23,6 +23,14 @@
 //! We suggest using implicit certificates instead of HDKD when 
 //! using VRFs.
 //!
+//! We also provide Tor v3 style key blinding via `PublicKey::blind`
+//! and `Keypair::blind`, which multiply the key by a blinding factor
+//! derived from a `SigningTranscript` and the public key itself, so
+//! anyone who knows both the public key and the transcript computes
+//! the same blinded public key, but blinded keys for different
+//! transcripts remain unlinkable to one another and to the original
+//! key, without requiring any chain code.  Our warning about malleable
+//! VRF outputs applies to blinded keys too.
 //! 
 
 // use curve25519_dalek::digest::generic_array::typenum::U64;
@@ -284,6 +292,63 @@ impl ExtendedKey<SecretKey> {
     }
 }
 
+impl PublicKey {
+    /// Derive a blinding factor from a public key and a transcript.
+    ///
+    /// We update the signing transcript as a side effect.
+    fn blinding_factor<T>(&self, t: &mut T) -> Scalar
+    where T: SigningTranscript
+    {
+        t.proto_name(b"KeyBlinding");
+        t.commit_point(b"blind:pk",self.as_compressed());
+        t.challenge_scalar(b"blind:factor")
+    }
+
+    /// Blind this public key by a factor derived from `t` and the key itself.
+    ///
+    /// Anyone who knows this public key and `t` obtains the same
+    /// blinded key, under which `Keypair::blind` with the same `t`
+    /// produces ordinary signatures.
+    pub fn blind<T>(&self, mut t: T) -> PublicKey
+    where T: SigningTranscript
+    {
+        let factor = self.blinding_factor(&mut t);
+        PublicKey::from_point(factor * self.as_point())
+    }
+}
+
+impl SecretKey {
+    /// Blind this secret key by a factor derived from `t` and the
+    /// corresponding public key, like `PublicKey::blind`.
+    pub fn blind<T>(&self, mut t: T, public_key: &PublicKey) -> SecretKey
+    where T: SigningTranscript
+    {
+        let factor = public_key.blinding_factor(&mut t);
+
+        // As in `derive_secret_key`, our nonce must be independent from
+        // the blinding factor, but need not follow any specification.
+        let mut nonce = [0u8; 32];
+        t.witness_bytes(b"blind:nonce", &mut nonce, &[&self.nonce, &self.to_bytes() as &[u8]]);
+
+        SecretKey {
+            key: factor * self.key,
+            nonce,
+        }
+    }
+}
+
+impl Keypair {
+    /// Blind this keypair by a factor derived from `t` and our public key,
+    /// yielding the keypair of `self.public.blind(t)`.
+    pub fn blind<T>(&self, t: T) -> Keypair
+    where T: SigningTranscript
+    {
+        let secret = self.secret.blind(t, &self.public);
+        let public = secret.to_public();
+        Keypair { secret, public }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use sha3::digest::{Update}; // ExtendableOutput,XofReader
@@ -345,4 +410,26 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn blind_key_sign_verify() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+        let key = Keypair::generate_with(&mut csprng);
+        let ctx = signing_context(b"service descriptor");
+
+        let period1 = signing_context(b"time period").bytes(b"1");
+        let period2 = signing_context(b"time period").bytes(b"2");
+        let blinded = key.blind(period1.clone());
+        assert_eq!(blinded.public, key.public.blind(period1),
+            "Public and secret key blinding missmatch!");
+        assert_ne!(blinded.public, key.public.blind(period2));
+        assert_ne!(blinded.public, key.public);
+
+        let sig = blinded.sign(ctx.bytes(b"descriptor"));
+        assert!( blinded.public.verify(ctx.bytes(b"descriptor"), &sig).is_ok(),
+            "Verification of a valid signature under a blinded key failed!" );
+        assert!( key.public.verify(ctx.bytes(b"descriptor"), &sig).is_err(),
+            "Verification of a blinded signature under the original key passed!" );
+    }
 }
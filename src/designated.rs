@@ -0,0 +1,213 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Designated verifier signatures
+//!
+//! A `DesignatedSignature` authenticates a transcript from its signer
+//! to one designated verifier, but convinces nobody else, because the
+//! designated verifier could produce identical signatures themselves.
+//! We thus obtain deniable authentication for off-chain messages.
+//!
+//! We derive the signature from the transcript, both public keys, a
+//! random salt, and the Diffie-Hellman key exchange between signer and
+//! verifier, which both parties can compute, but nobody else can.
+//! We thus build on `SecretKey::raw_key_exchange`, much like aead.rs,
+//! but only require the signer's and verifier's secret keys to be
+//! secure, not any AEAD.
+//!
+//! We salt signatures so that observers cannot link signatures on
+//! identical transcripts, but salts do not affect deniability since
+//! the designated verifier may choose salts arbitrarily too.
+
+use merlin::Transcript;
+
+use subtle::ConstantTimeEq;
+
+use super::*;
+use crate::context::SigningTranscript;
+
+
+/// The length of a `DesignatedSignature`, in bytes.
+pub const DESIGNATED_SIGNATURE_LENGTH: usize = 64;
+
+/// A signature that only one designated verifier can check, and
+/// which that verifier could have created themselves.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DesignatedSignature {
+    /// Random salt
+    pub (crate) salt: [u8; 32],
+    /// Tag derived from the transcript, public keys, salt, and key exchange
+    pub (crate) tag: [u8; 32],
+}
+
+impl core::fmt::Debug for DesignatedSignature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DesignatedSignature( salt: {:?}, tag: {:?} )", &self.salt, &self.tag)
+    }
+}
+
+impl DesignatedSignature {
+    const DESCRIPTION : &'static str = "A 64 byte designated verifier signature";
+
+    /// Convert this `DesignatedSignature` to a byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; DESIGNATED_SIGNATURE_LENGTH] {
+        let mut bytes: [u8; DESIGNATED_SIGNATURE_LENGTH] = [0u8; DESIGNATED_SIGNATURE_LENGTH];
+        bytes[..32].copy_from_slice(&self.salt[..]);
+        bytes[32..].copy_from_slice(&self.tag[..]);
+        bytes
+    }
+
+    /// Construct a `DesignatedSignature` from a slice of bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<DesignatedSignature> {
+        if bytes.len() != DESIGNATED_SIGNATURE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "DesignatedSignature",
+                description: DesignatedSignature::DESCRIPTION,
+                length: DESIGNATED_SIGNATURE_LENGTH
+            });
+        }
+
+        let mut salt: [u8; 32] = [0u8; 32];
+        let mut tag: [u8; 32] = [0u8; 32];
+        salt.copy_from_slice(&bytes[..32]);
+        tag.copy_from_slice(&bytes[32..]);
+
+        Ok(DesignatedSignature{ salt, tag })
+    }
+}
+
+serde_boilerplate!(DesignatedSignature);
+
+
+/// Compute the tag of a designated verifier signature, given the
+/// transcript, both public keys, the salt, and their key exchange.
+fn designated_tag<T>(
+    mut t: T,
+    signer: &PublicKey,
+    verifier: &PublicKey,
+    salt: &[u8; 32],
+    secret: &SecretKey,
+    other: &PublicKey,
+) -> [u8; 32]
+where T: SigningTranscript
+{
+    t.proto_name(b"Designated-sig");
+    t.commit_point(b"dv:signer",signer.as_compressed());
+    t.commit_point(b"dv:verifier",verifier.as_compressed());
+    t.commit_bytes(b"dv:salt",salt);
+    t.commit_point(b"dv:dh",&secret.raw_key_exchange(other));
+    let mut tag = [0u8; 32];
+    t.challenge_bytes(b"dv:tag",&mut tag);
+    tag
+}
+
+impl SecretKey {
+    /// Sign a transcript with this `SecretKey` so that only `verifier`
+    /// can check the signature.
+    ///
+    /// Requires the public key corresponding to `self`, like `sign`.
+    pub fn sign_for<T>(&self, t: T, public_key: &PublicKey, verifier: &PublicKey) -> DesignatedSignature
+    where T: SigningTranscript
+    {
+        let mut salt = [0u8; 32];
+        let mut t0 = Transcript::new(b"Designated-salt");
+        t0.commit_point(b"dv:verifier",verifier.as_compressed());
+        t0.witness_bytes(b"dv:salt",&mut salt,&[&self.nonce]);
+        let tag = designated_tag(t, public_key, verifier, &salt, self, verifier);
+        DesignatedSignature { salt, tag }
+    }
+
+    /// Verify a designated verifier signature by `signer` on a
+    /// transcript, using this `SecretKey` of the designated verifier.
+    ///
+    /// Requires the public key corresponding to `self`.
+    pub fn verify_designated<T>(&self, t: T, public_key: &PublicKey, signer: &PublicKey, signature: &DesignatedSignature)
+     -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        let tag = designated_tag(t, signer, public_key, &signature.salt, self, signer);
+        if bool::from(tag.ct_eq(&signature.tag)) { Ok(()) } else { Err(SignatureError::EquationFalse) }
+    }
+
+    /// Simulate a designated verifier signature by `signer` on a
+    /// transcript, using this `SecretKey` of the designated verifier.
+    ///
+    /// We produce signatures distributed identically to those from
+    /// `sign_for`, which makes designated verifier signatures deniable.
+    pub fn simulate_designated<T>(&self, t: T, public_key: &PublicKey, signer: &PublicKey) -> DesignatedSignature
+    where T: SigningTranscript
+    {
+        let mut salt = [0u8; 32];
+        let mut t0 = Transcript::new(b"Designated-simulated-salt");
+        t0.commit_point(b"dv:signer",signer.as_compressed());
+        t0.witness_bytes(b"dv:salt",&mut salt,&[&self.nonce]);
+        let tag = designated_tag(t, signer, public_key, &salt, self, signer);
+        DesignatedSignature { salt, tag }
+    }
+}
+
+impl Keypair {
+    /// Sign a transcript with this keypair so that only `verifier`
+    /// can check the signature.
+    pub fn sign_for<T>(&self, t: T, verifier: &PublicKey) -> DesignatedSignature
+    where T: SigningTranscript
+    {
+        self.secret.sign_for(t, &self.public, verifier)
+    }
+
+    /// Verify a designated verifier signature by `signer`, which
+    /// designates this keypair as its verifier.
+    pub fn verify_designated<T>(&self, t: T, signer: &PublicKey, signature: &DesignatedSignature)
+     -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        self.secret.verify_designated(t, &self.public, signer, signature)
+    }
+
+    /// Simulate a designated verifier signature by `signer`, which
+    /// designates this keypair as its verifier.
+    pub fn simulate_designated<T>(&self, t: T, signer: &PublicKey) -> DesignatedSignature
+    where T: SigningTranscript
+    {
+        self.secret.simulate_designated(t, &self.public, signer)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn designated_sign_verify_simulate() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let alice = Keypair::generate_with(&mut csprng);
+        let bob = Keypair::generate_with(&mut csprng);
+        let eve = Keypair::generate_with(&mut csprng);
+        let ctx = signing_context(b"off-chain chat");
+
+        let sig = alice.sign_for(ctx.bytes(b"hi bob"), &bob.public);
+        let sig = DesignatedSignature::from_bytes(&sig.to_bytes()[..]).unwrap();
+        assert!( bob.verify_designated(ctx.bytes(b"hi bob"), &alice.public, &sig).is_ok(),
+            "Verification of a valid designated verifier signature failed!" );
+        assert!( bob.verify_designated(ctx.bytes(b"hi eve"), &alice.public, &sig).is_err(),
+            "Verification of a designated verifier signature on a different message passed!" );
+        assert!( eve.verify_designated(ctx.bytes(b"hi bob"), &alice.public, &sig).is_err(),
+            "Verification of a designated verifier signature by another verifier passed!" );
+        assert!( bob.verify_designated(ctx.bytes(b"hi bob"), &eve.public, &sig).is_err(),
+            "Verification of a designated verifier signature by another signer passed!" );
+        assert_ne!( sig, alice.sign_for(ctx.bytes(b"hi bob"), &bob.public) );
+
+        // Bob could have produced an equally convincing signature himself.
+        let fake = bob.simulate_designated(ctx.bytes(b"hi bob"), &alice.public);
+        assert!( bob.verify_designated(ctx.bytes(b"hi bob"), &alice.public, &fake).is_ok(),
+            "Verification of a simulated designated verifier signature failed!" );
+    }
+}
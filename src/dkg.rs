@@ -0,0 +1,586 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Pedersen distributed key generation for threshold keys
+//!
+//! We let `n` participants jointly generate a group `PublicKey` along
+//! with one `FrostKeyShare` each, of which any `threshold` can sign
+//! using frost.rs, but with no trusted dealer.  We follow the Pedersen
+//! DKG as used in "FROST: Flexible Round-Optimized Schnorr Threshold
+//! Signatures" by Chelsea Komlo and Ian Goldberg
+//! https://eprint.iacr.org/2020/852 along with the complaint handling
+//! from "Secure Distributed Key Generation for Discrete-Log Based
+//! Cryptosystems" by Rosario Gennaro, Stanisław Jarecki, Hugo Krawczyk,
+//! and Tal Rabin.
+//!
+//! Our protocol runs in three rounds, which we model with stage types
+//! like musig.rs:
+//!
+//! 1. Every participant broadcasts a `DkgCommitment` consisting of a
+//!    verification vector for a secret random polynomial, along with a
+//!    Fiat-Shamir proof of knowledge of its constant term over the
+//!    caller's `SigningTranscript`, which prevents rogue key attacks.
+//! 2. Every participant sends every other participant their share of
+//!    this polynomial as a `DkgShare`, which recipients check against
+//!    the sender's verification vector.
+//! 3. Every participant broadcasts a `DkgComplaint` for each missing or
+//!    invalid share, to which the accused must respond by broadcasting
+//!    the disputed share.  We disqualify anyone whose response fails
+//!    verification or who never responds.
+//!
+//! We report errors with the `MultiSignatureStage` of musig.rs, using
+//! `Commitment` for the first round, `Reveal` for the second, and
+//! `Cosignature` for complaints.
+//!
+//! We require an authenticated broadcast channel for commitments,
+//! complaints, and responses, as well as authenticated and encrypted
+//! channels for `DkgShare`s, all of which callers must provide.
+
+use std::{collections::btree_map::{BTreeMap, Entry}, collections::BTreeSet, vec::Vec};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use zeroize::Zeroize;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::errors::MultiSignatureStage;
+use crate::frost::{FrostKeyShare,ThresholdPublicKey};
+use crate::points::vss::{evaluate_commitments,evaluate_polynomial};
+
+
+/// Check a share `value` for `index` against a verification vector.
+fn check_share(vss: &[RistrettoPoint], index: u16, value: &Scalar) -> bool {
+    value * &constants::RISTRETTO_BASEPOINT_TABLE == evaluate_commitments(vss, index)
+}
+
+/// Transcript for proofs of knowledge of a polynomial's constant term.
+fn proof_of_knowledge_transcript<T>(mut t: T, index: u16, constant: &CompressedRistretto) -> T
+where T: SigningTranscript
+{
+    t.proto_name(b"DKG-PoK");
+    t.commit_bytes(b"dkg:index", &index.to_le_bytes());
+    t.commit_point(b"dkg:C0", constant);
+    t
+}
+
+
+// === Messages === //
+
+/// Broadcast verification vector and proof of knowledge of its constant term
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DkgCommitment {
+    /// Commitments `C_k = a_k B` to the polynomial coefficients `a_k`
+    vss: Vec<CompressedRistretto>,
+    /// Proof commitment `R = r B`
+    R: CompressedRistretto,
+    /// Proof response `mu = r + c a_0`
+    mu: Scalar,
+}
+
+impl DkgCommitment {
+    const DESCRIPTION : &'static str = "A DKG verification vector followed by a 64 byte proof of knowledge";
+
+    /// Convert this `DkgCommitment` to bytes, consisting of the
+    /// verification vector followed by the proof of knowledge.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * self.vss.len() + 64);
+        for c in self.vss.iter() {
+            bytes.extend_from_slice(c.as_bytes());
+        }
+        bytes.extend_from_slice(self.R.as_bytes());
+        bytes.extend_from_slice(self.mu.as_bytes());
+        bytes
+    }
+
+    /// Construct a `DkgCommitment` from bytes produced by `to_bytes`.
+    #[allow(non_snake_case)]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<DkgCommitment> {
+        if bytes.len() < 96 || bytes.len() % 32 != 0 {
+            return Err(SignatureError::BytesLengthError {
+                name: "DkgCommitment",
+                description: DkgCommitment::DESCRIPTION,
+                length: 0,
+            });
+        }
+        let (vss, proof) = bytes.split_at(bytes.len() - 64);
+        let vss = vss.chunks(32).map(CompressedRistretto::from_slice).collect();
+        let R = CompressedRistretto::from_slice(&proof[..32]);
+        let mut mu = [0u8; 32];
+        mu.copy_from_slice(&proof[32..]);
+        let mu = Scalar::from_canonical_bytes(mu).ok_or(SignatureError::ScalarFormatError) ?;
+        Ok(DkgCommitment { vss, R, mu })
+    }
+
+    /// Check the proof of knowledge, and decompress the verification
+    /// vector, which must have `threshold` entries.
+    #[allow(non_snake_case)]
+    fn verify<T>(&self, t: T, index: u16, threshold: u16) -> SignatureResult<Vec<RistrettoPoint>>
+    where T: SigningTranscript
+    {
+        if self.vss.len() != threshold as usize {
+            return Err(SignatureError::BytesLengthError {
+                name: "DkgCommitment",
+                description: DkgCommitment::DESCRIPTION,
+                length: 32 * threshold as usize + 64,
+            });
+        }
+        let vss = self.vss.iter()
+            .map(|c| c.decompress().ok_or(SignatureError::PointDecompressionError))
+            .collect::<SignatureResult<Vec<RistrettoPoint>>>() ?;
+
+        let mut t = proof_of_knowledge_transcript(t, index, &self.vss[0]);
+        t.commit_point(b"dkg:R", &self.R);
+        let c: Scalar = t.challenge_scalar(b"dkg:c");
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, &(-vss[0]), &self.mu);
+        if R.compress() == self.R {
+            Ok(vss)
+        } else {
+            let musig_stage = MultiSignatureStage::Commitment;
+            Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, })
+        }
+    }
+}
+
+serde_boilerplate!(DkgCommitment);
+
+/// Length of a `DkgShare`, in bytes.
+pub const DKG_SHARE_LENGTH: usize = 36;
+
+/// One participant's secret share for another participant
+#[derive(Clone)]
+pub struct DkgShare {
+    /// Index of the participant who created this share
+    from: u16,
+    /// Index of the participant for whom we created this share
+    to: u16,
+    /// Evaluation of the sender's polynomial at `to`
+    value: Scalar,
+}
+
+impl core::fmt::Debug for DkgShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DkgShare( from: {}, to: {} )", self.from, self.to)
+    }
+}
+
+impl Drop for DkgShare {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl DkgShare {
+    const DESCRIPTION : &'static str = "A 36 byte DKG secret share";
+
+    /// Index of the participant who created this share
+    pub fn from(&self) -> u16 { self.from }
+
+    /// Index of the participant for whom we created this share
+    pub fn to(&self) -> u16 { self.to }
+
+    /// Convert this `DkgShare` to bytes, consisting of the sender's
+    /// and recipient's indices in little endian, and then the share.
+    pub fn to_bytes(&self) -> [u8; DKG_SHARE_LENGTH] {
+        let mut bytes = [0u8; DKG_SHARE_LENGTH];
+        bytes[..2].copy_from_slice(&self.from.to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.to.to_le_bytes());
+        bytes[4..].copy_from_slice(self.value.as_bytes());
+        bytes
+    }
+
+    /// Construct a `DkgShare` from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<DkgShare> {
+        if bytes.len() != DKG_SHARE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "DkgShare",
+                description: DkgShare::DESCRIPTION,
+                length: DKG_SHARE_LENGTH,
+            });
+        }
+        let mut value = [0u8; 32];
+        value.copy_from_slice(&bytes[4..]);
+        Ok(DkgShare {
+            from: u16::from_le_bytes([bytes[0], bytes[1]]),
+            to: u16::from_le_bytes([bytes[2], bytes[3]]),
+            value: Scalar::from_canonical_bytes(value).ok_or(SignatureError::ScalarFormatError) ?,
+        })
+    }
+}
+
+/// Broadcast accusation that `accused` sent `accuser` no valid share
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DkgComplaint {
+    /// Index of the participant who lacks a valid share
+    pub accuser: u16,
+    /// Index of the participant who owes that share
+    pub accused: u16,
+}
+
+
+// === Distributed key generation protocol === //
+
+/// Our secret polynomial and the shares others sent us, zeroized on drop.
+struct DkgSecrets {
+    coefficients: Vec<Scalar>,
+    nonce: [u8; 32],
+    received: BTreeMap<u16,Scalar>,
+}
+
+impl Drop for DkgSecrets {
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+        self.nonce.zeroize();
+        for value in self.received.values_mut() {
+            value.zeroize();
+        }
+    }
+}
+
+/// Distributed key generation container generic over its session types
+pub struct Dkg<T: SigningTranscript+Clone,S> {
+    t: T,
+    index: u16,
+    threshold: u16,
+    participants: u16,
+    secrets: DkgSecrets,
+    vss: BTreeMap<u16,Vec<RistrettoPoint>>,
+    stage: S,
+}
+
+impl<T: SigningTranscript+Clone,S> Dkg<T,S> {
+    /// Our nonzero index
+    pub fn index(&self) -> u16 { self.index }
+
+    /// Iterate over the indices of participants whose commitments we accepted.
+    pub fn committed(&self) -> impl Iterator<Item=u16> + '_ {
+        self.vss.keys().cloned()
+    }
+
+    /// Our share for the participant with the given index
+    fn share_for(&self, to: u16) -> DkgShare {
+        DkgShare { from: self.index, to, value: evaluate_polynomial(&self.secrets.coefficients, to) }
+    }
+}
+
+/// Initial stage during which participants broadcast commitments
+pub struct CommitStage {
+    commitment: DkgCommitment,
+}
+
+impl<T: SigningTranscript+Clone> Dkg<T,CommitStage> {
+    /// Initialize a distributed key generation as the participant
+    /// `index` among `participants` with indices `1..=participants`,
+    /// any `threshold` of whom shall later sign.
+    ///
+    /// All participants must use the same transcript `t`, which
+    /// should identify this session.  We panic unless
+    /// `1 <= index <= participants` and `1 <= threshold <= participants`.
+    #[allow(non_snake_case)]
+    pub fn new(t: T, index: u16, threshold: u16, participants: u16) -> Dkg<T,CommitStage> {
+        assert!(1 <= index && index <= participants, "DKG requires 1 <= index <= participants.");
+        assert!(1 <= threshold && threshold <= participants, "DKG requires 1 <= threshold <= participants.");
+
+        let mut t0 = t.clone();
+        t0.proto_name(b"DKG-secrets");
+        t0.commit_bytes(b"dkg:index", &index.to_le_bytes());
+        let coefficients: Vec<Scalar> = (0..threshold)
+            .map(|k| t0.witness_scalar(b"dkg:coefficient", &[&k.to_le_bytes()]))
+            .collect();
+        let mut nonce = [0u8; 32];
+        t0.witness_bytes(b"dkg:nonce", &mut nonce, &[]);
+
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        let vss: Vec<RistrettoPoint> = coefficients.iter().map(|a| a * B).collect();
+        let compressed: Vec<CompressedRistretto> = vss.iter().map(|c| c.compress()).collect();
+
+        let mut pok = proof_of_knowledge_transcript(t.clone(), index, &compressed[0]);
+        let mut r = pok.witness_scalar(b"dkg:pok-nonce", &[&coefficients[0].to_bytes()]);
+        let R = (&r * B).compress();
+        pok.commit_point(b"dkg:R", &R);
+        let c: Scalar = pok.challenge_scalar(b"dkg:c");
+        let mu = r + c * coefficients[0];
+        r.zeroize();
+
+        let mut vss_map = BTreeMap::new();
+        vss_map.insert(index, vss);
+        let secrets = DkgSecrets { coefficients, nonce, received: BTreeMap::new() };
+        let stage = CommitStage { commitment: DkgCommitment { vss: compressed, R, mu } };
+        Dkg { t, index, threshold, participants, secrets, vss: vss_map, stage, }
+    }
+
+    /// Our commitment to broadcast to all other participants
+    pub fn our_commitment(&self) -> &DkgCommitment { &self.stage.commitment }
+
+    /// Verify and add another participant's commitment.
+    ///
+    /// We return `MuSigInconsistent` with `duplicate: false` if their
+    /// proof of knowledge fails, in which case we exclude them.
+    pub fn add_their_commitment(&mut self, index: u16, theirs: &DkgCommitment) -> SignatureResult<()> {
+        let musig_stage = MultiSignatureStage::Commitment;
+        if index == 0 || index > self.participants {
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        let vss = theirs.verify(self.t.clone(), index, self.threshold) ?;
+        match self.vss.entry(index) {
+            Entry::Vacant(v) => { v.insert(vss); },
+            Entry::Occupied(o) =>
+                if o.get() != &vss {
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Commitment to share distribution phase transition.
+    ///
+    /// We return `MuSigAbsent` if fewer than threshold many
+    /// participants committed.
+    pub fn share_stage(self) -> SignatureResult<Dkg<T,ShareStage>> {
+        if self.vss.len() < self.threshold as usize {
+            let musig_stage = MultiSignatureStage::Commitment;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        let Dkg { t, index, threshold, participants, mut secrets, vss, .. } = self;
+        let own = evaluate_polynomial(&secrets.coefficients, index);
+        secrets.received.insert(index, own);
+        Ok(Dkg { t, index, threshold, participants, secrets, vss, stage: ShareStage, })
+    }
+}
+
+/// Stage during which participants exchange secret shares
+pub struct ShareStage;
+
+impl<T: SigningTranscript+Clone> Dkg<T,ShareStage> {
+    /// Our shares to send privately to every other participant who committed
+    pub fn our_shares(&self) -> Vec<DkgShare> {
+        self.committed().filter(|i| *i != self.index).map(|i| self.share_for(i)).collect()
+    }
+
+    /// Verify and add a share another participant sent us.
+    ///
+    /// We return `MuSigInconsistent` with `duplicate: false` if their
+    /// share fails verification, in which case we shall complain.
+    pub fn add_their_share(&mut self, share: &DkgShare) -> SignatureResult<()> {
+        let musig_stage = MultiSignatureStage::Reveal;
+        let vss = match self.vss.get(&share.from) {
+            Some(vss) if share.to == self.index => vss,
+            _ => {
+                let musig_stage = MultiSignatureStage::Commitment;
+                return Err(SignatureError::MuSigAbsent { musig_stage, });
+            },
+        };
+        if ! check_share(vss, self.index, &share.value) {
+            return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, });
+        }
+        match self.secrets.received.entry(share.from) {
+            Entry::Vacant(v) => { v.insert(share.value); },
+            Entry::Occupied(o) =>
+                if o.get() != &share.value {
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Share distribution to complaint phase transition, in which we
+    /// complain about every committed participant who sent us no valid share.
+    pub fn complaint_stage(self) -> Dkg<T,ComplaintStage> {
+        let Dkg { t, index, threshold, participants, secrets, vss, .. } = self;
+        let ours: Vec<DkgComplaint> = vss.keys()
+            .filter(|i| ! secrets.received.contains_key(i))
+            .map(|accused| DkgComplaint { accuser: index, accused: *accused })
+            .collect();
+        let pending = ours.iter().map(|c| (c.accuser, c.accused)).collect();
+        let stage = ComplaintStage { ours, pending, disqualified: BTreeSet::new() };
+        Dkg { t, index, threshold, participants, secrets, vss, stage, }
+    }
+}
+
+/// Stage during which participants resolve complaints
+pub struct ComplaintStage {
+    ours: Vec<DkgComplaint>,
+    pending: BTreeSet<(u16,u16)>,
+    disqualified: BTreeSet<u16>,
+}
+
+impl<T: SigningTranscript+Clone> Dkg<T,ComplaintStage> {
+    /// Our complaints to broadcast to all other participants
+    pub fn our_complaints(&self) -> &[DkgComplaint] { &self.stage.ours }
+
+    /// Record another participant's complaint.
+    pub fn add_their_complaint(&mut self, complaint: DkgComplaint) -> SignatureResult<()> {
+        if ! self.vss.contains_key(&complaint.accused) || complaint.accuser == 0 || complaint.accuser > self.participants {
+            let musig_stage = MultiSignatureStage::Commitment;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        self.stage.pending.insert((complaint.accuser, complaint.accused));
+        Ok(())
+    }
+
+    /// Our responses to broadcast for every complaint against us,
+    /// which reveal the disputed shares.
+    pub fn our_responses(&self) -> Vec<DkgShare> {
+        self.stage.pending.iter()
+            .filter(|(_,accused)| *accused == self.index)
+            .map(|(accuser,_)| self.share_for(*accuser))
+            .collect()
+    }
+
+    /// Verify another participant's response to a complaint against them,
+    /// adopting the revealed share if it was ours.
+    ///
+    /// We return `MuSigInconsistent` with `duplicate: false` if the
+    /// revealed share fails verification, and disqualify its sender.
+    pub fn add_their_response(&mut self, share: &DkgShare) -> SignatureResult<()> {
+        if ! self.stage.pending.contains(&(share.to, share.from)) {
+            let musig_stage = MultiSignatureStage::Cosignature;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        if ! check_share(&self.vss[&share.from], share.to, &share.value) {
+            self.stage.disqualified.insert(share.from);
+            let musig_stage = MultiSignatureStage::Cosignature;
+            return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, });
+        }
+        self.stage.pending.remove(&(share.to, share.from));
+        if share.to == self.index {
+            self.secrets.received.insert(share.from, share.value);
+        }
+        Ok(())
+    }
+
+    /// Iterate over participants disqualified so far, including
+    /// everyone with unanswered complaints.
+    pub fn disqualified(&self) -> impl Iterator<Item=u16> + '_ {
+        let unanswered = self.stage.pending.iter().map(|(_,accused)| *accused);
+        self.stage.disqualified.iter().cloned().chain(unanswered)
+            .collect::<BTreeSet<u16>>().into_iter()
+    }
+
+    /// Complete the distributed key generation, yielding our share of
+    /// the group key, contributed by all participants who committed
+    /// and were not disqualified.
+    ///
+    /// We return `MuSigAbsent` if fewer than threshold many
+    /// participants qualified.
+    pub fn finish(self) -> SignatureResult<FrostKeyShare> {
+        let disqualified: BTreeSet<u16> = self.disqualified().collect();
+        let qualified: Vec<u16> = self.committed().filter(|i| ! disqualified.contains(i)).collect();
+        if qualified.len() < self.threshold as usize {
+            let musig_stage = MultiSignatureStage::Cosignature;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+
+        let mut key = Scalar::zero();
+        for i in qualified.iter() {
+            // Resolving all complaints ensures we hold shares from all qualified participants.
+            key += self.secrets.received[i];
+        }
+        let secret = SecretKey { key, nonce: self.secrets.nonce };
+        key.zeroize();
+
+        let group = PublicKey::from_point(qualified.iter().map(|i| self.vss[i][0]).sum());
+        let shares = (1..=self.participants).map(|m| {
+            let point = qualified.iter().map(|i| evaluate_commitments(&self.vss[i], m)).sum();
+            (m, PublicKey::from_point(point))
+        }).collect();
+        let public = ThresholdPublicKey { threshold: self.threshold, group, shares };
+        Ok(FrostKeyShare { index: self.index, secret, public })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a complete DKG among in-memory participants, where the
+    /// participant `cheater` sends a corrupted share to participant 1,
+    /// and answers the resulting complaint if `cheater_responds`.
+    fn run_dkg(threshold: u16, n: u16, cheater: Option<u16>, cheater_responds: bool) -> Vec<FrostKeyShare> {
+        let t = signing_context(b"dkg test").bytes(b"session 1");
+        let mut commits: Vec<_> = (1..=n).map(|i| Dkg::new(t.clone(), i, threshold, n)).collect();
+        let commitments: Vec<DkgCommitment> = commits.iter().map(|c| c.our_commitment().clone()).collect();
+        for (i,c) in (1..=n).zip(commitments.iter()) {
+            for j in commits.iter_mut() {
+                j.add_their_commitment(i, c).unwrap();
+            }
+        }
+
+        let mut shares: Vec<_> = commits.into_iter().map(|c| c.share_stage().unwrap()).collect();
+        let messages: Vec<DkgShare> = shares.iter().flat_map(|s| s.our_shares()).collect();
+        for mut m in messages {
+            let corrupt = Some(m.from) == cheater && m.to == 1;
+            if corrupt { m.value += Scalar::one(); }
+            let recipient = &mut shares[m.to as usize - 1];
+            assert_eq!(recipient.add_their_share(&m).is_err(), corrupt);
+        }
+
+        let mut complaints: Vec<_> = shares.into_iter().map(|s| s.complaint_stage()).collect();
+        let accusations: Vec<DkgComplaint> = complaints.iter().flat_map(|c| c.our_complaints().to_vec()).collect();
+        assert_eq!(accusations.len(), cheater.is_some() as usize);
+        for a in accusations {
+            for c in complaints.iter_mut() {
+                c.add_their_complaint(a).unwrap();
+            }
+        }
+        let responses: Vec<DkgShare> = complaints.iter()
+            .filter(|c| cheater_responds || Some(c.index()) != cheater)
+            .flat_map(|c| c.our_responses())
+            .collect();
+        for r in responses {
+            for c in complaints.iter_mut() {
+                c.add_their_response(&r).unwrap();
+            }
+        }
+        complaints.into_iter().map(|c| c.finish().unwrap()).collect()
+    }
+
+    fn check_threshold_signing(shares: &[FrostKeyShare]) {
+        let public = shares[0].threshold_public_key();
+        for s in shares.iter() {
+            assert_eq!(s.threshold_public_key(), public, "Participants disagree on the group key!");
+            assert_eq!(&s.secret_key().to_public(), public.verification_share(s.index()).unwrap());
+        }
+
+        let t = signing_context(b"dkg test").bytes(b"threshold signed");
+        let signing = &shares[shares.len() - public.threshold() as usize..];
+        let mut commits: Vec<_> = signing.iter().map(|s| s.frost(t.clone())).collect();
+        let commitments: Vec<_> = commits.iter().map(|c| c.our_commitment()).collect();
+        for (s,r) in signing.iter().zip(commitments) {
+            for j in commits.iter_mut() {
+                j.add_their_commitment(s.index(), r).unwrap();
+            }
+        }
+        let mut signs: Vec<_> = commits.into_iter().map(|c| c.sign_stage().unwrap()).collect();
+        let partials: Vec<_> = signs.iter().map(|s| s.our_partial_signature()).collect();
+        for (s,p) in signing.iter().zip(partials) {
+            for j in signs.iter_mut() {
+                j.add_their_partial_signature(s.index(), p).unwrap();
+            }
+        }
+        let signature = signs[0].sign().unwrap();
+        assert!( public.public_key().verify(t, &signature).is_ok(),
+            "Verification of a threshold signature from DKG shares failed!" );
+    }
+
+    #[test]
+    fn dkg_honest_and_complaints() {
+        check_threshold_signing(&run_dkg(3, 5, None, false));
+
+        // A cheater who answers the complaint remains qualified.
+        let shares = run_dkg(2, 4, Some(3), true);
+        check_threshold_signing(&shares);
+
+        // A cheater who ignores the complaint gets disqualified, yet
+        // everyone still agrees upon a working group key.
+        let shares = run_dkg(2, 4, Some(3), false);
+        check_threshold_signing(&shares);
+    }
+}
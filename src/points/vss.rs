@@ -0,0 +1,493 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Feldman and Pedersen verifiable secret sharing on Ristretto
+//!
+//! A dealer shares a secret `Scalar` using a random polynomial of
+//! degree `threshold - 1`, whose constant term is the secret, and
+//! publishes commitments to the polynomial's coefficients as
+//! `RistrettoPoint`s, against which every recipient checks their share.
+//!
+//! Feldman commitments `a_k B` reveal the public key `a_0 B` of the
+//! secret, and yield every participant's public share as a `PublicKey`,
+//! under which partial signatures by that participant verify.
+//!
+//! Pedersen commitments `a_k B + b_k H` hide the secret perfectly,
+//! because they blind every coefficient by a second polynomial, but
+//! thus yield no public shares.  We hash `H` to the curve, so nobody
+//! knows its discrete logarithm with respect to `B`.
+//!
+//! We require authenticated and encrypted channels for shares, and a
+//! broadcast channel for commitments, all of which callers must provide.
+
+use core::iter;
+
+use rand_core::{RngCore,CryptoRng};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::errors::{SignatureError,SignatureResult};
+use crate::keys::{PublicKey,SecretKey};
+use super::RISTRETTO_POINT_LENGTH;
+
+
+/// The length of a `SecretShare`, in bytes.
+pub const SECRET_SHARE_LENGTH: usize = 2 + 32;
+
+/// The length of a `PedersenShare`, in bytes.
+pub const PEDERSEN_SHARE_LENGTH: usize = 2 + 32 + 32;
+
+
+// === Polynomial helpers === //
+
+/// Evaluate the polynomial with the given coefficients at `index`.
+pub(crate) fn evaluate_polynomial(coefficients: &[Scalar], index: u16) -> Scalar {
+    let x = Scalar::from(index as u64);
+    coefficients.iter().rev().fold(Scalar::zero(), |acc, a| acc * x + a)
+}
+
+/// Evaluate commitments to a polynomial's coefficients at `index`,
+/// yielding the commitment to the polynomial's value at `index`.
+pub(crate) fn evaluate_commitments(commitments: &[RistrettoPoint], index: u16) -> RistrettoPoint {
+    let x = Scalar::from(index as u64);
+    commitments.iter().rev().fold(RistrettoPoint::default(), |acc, c| acc * x + c)
+}
+
+/// Sample a uniformly random scalar.
+fn random_scalar<R: RngCore+CryptoRng>(rng: &mut R) -> Scalar {
+    let mut bytes = [0u8; 64];
+    rng.fill_bytes(&mut bytes);
+    let s = Scalar::from_bytes_mod_order_wide(&bytes);
+    bytes.zeroize();
+    s
+}
+
+/// Sample a random polynomial of degree `threshold - 1` with the
+/// given constant term.
+fn random_polynomial<R>(constant: Scalar, threshold: u16, rng: &mut R) -> Vec<Scalar>
+where R: RngCore+CryptoRng
+{
+    iter::once(constant)
+        .chain((1..threshold).map(|_| random_scalar(rng)))
+        .collect()
+}
+
+/// Second generator `H` for Pedersen commitments, hashed to the curve.
+pub fn pedersen_generator() -> RistrettoPoint {
+    let mut t = merlin::Transcript::new(b"PedersenVSS");
+    let mut b = [0u8; 64];
+    t.challenge_bytes(b"generator", &mut b);
+    RistrettoPoint::from_uniform_bytes(&b)
+}
+
+/// Compress commitments into bytes.
+fn commitments_to_bytes(commitments: &[RistrettoPoint]) -> Vec<u8> {
+    commitments.iter().flat_map(|c| c.compress().to_bytes()).collect()
+}
+
+/// Returns true if `n` coefficients give a threshold representable as a
+/// nonzero `u16`.
+fn valid_coefficient_count(n: usize) -> bool {
+    1 <= n && n <= u16::MAX as usize
+}
+
+/// Decompress commitments from bytes, for between one and `u16::MAX`
+/// of them.
+fn commitments_from_bytes(name: &'static str, description: &'static str, bytes: &[u8])
+ -> SignatureResult<Vec<RistrettoPoint>>
+{
+    if bytes.len() % RISTRETTO_POINT_LENGTH != 0
+        || ! valid_coefficient_count(bytes.len() / RISTRETTO_POINT_LENGTH)
+    {
+        return Err(SignatureError::BytesLengthError { name, description, length: 0 });
+    }
+    bytes.chunks(RISTRETTO_POINT_LENGTH)
+        .map(|c| CompressedRistretto::from_slice(c).decompress().ok_or(SignatureError::PointDecompressionError))
+        .collect()
+}
+
+/// Decode a share's nonzero index.
+fn index_from_bytes(bytes: &[u8]) -> SignatureResult<u16> {
+    let index = u16::from_le_bytes([bytes[0], bytes[1]]);
+    if index == 0 {
+        return Err(SignatureError::SecretShareInconsistent);
+    }
+    Ok(index)
+}
+
+/// Decode a canonically encoded scalar.
+fn scalar_from_bytes(bytes: &[u8]) -> SignatureResult<Scalar> {
+    let mut b = [0u8; 32];
+    b.copy_from_slice(bytes);
+    Scalar::from_canonical_bytes(b).ok_or(SignatureError::ScalarFormatError)
+}
+
+
+// === Feldman VSS === //
+
+/// One participant's share of a secret dealt by `FeldmanCommitment::deal`.
+#[derive(Clone,Zeroize)]
+#[zeroize(drop)]
+pub struct SecretShare {
+    /// Nonzero evaluation point of this share
+    index: u16,
+    /// Evaluation of the sharing polynomial at `index`
+    value: Scalar,
+}
+
+impl core::fmt::Debug for SecretShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretShare( index: {} )", self.index)
+    }
+}
+
+impl SecretShare {
+    const DESCRIPTION : &'static str = "A 34 byte share of a secret scalar";
+
+    /// Index of this share, between one and the number of participants
+    pub fn index(&self) -> u16 { self.index }
+
+    /// Evaluation of the sharing polynomial at our index
+    pub fn value(&self) -> &Scalar { &self.value }
+
+    /// Public share corresponding to this share
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_point(&self.value * &constants::RISTRETTO_BASEPOINT_TABLE)
+    }
+
+    /// Convert this share into a `SecretKey` with a fresh nonce seed,
+    /// so that its signatures verify under our public share.
+    pub fn to_secret_key<R>(&self, mut rng: R) -> SecretKey
+    where R: RngCore+CryptoRng
+    {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        SecretKey { key: self.value, nonce }
+    }
+
+    /// Convert this share to bytes, consisting of the little endian
+    /// index followed by the value.
+    pub fn to_bytes(&self) -> [u8; SECRET_SHARE_LENGTH] {
+        let mut bytes = [0u8; SECRET_SHARE_LENGTH];
+        bytes[..2].copy_from_slice(&self.index.to_le_bytes());
+        bytes[2..].copy_from_slice(self.value.as_bytes());
+        bytes
+    }
+
+    /// Construct a share from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<SecretShare> {
+        if bytes.len() != SECRET_SHARE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "SecretShare",
+                description: SecretShare::DESCRIPTION,
+                length: SECRET_SHARE_LENGTH,
+            });
+        }
+        Ok(SecretShare {
+            index: index_from_bytes(&bytes[..2]) ?,
+            value: scalar_from_bytes(&bytes[2..]) ?,
+        })
+    }
+}
+
+serde_boilerplate!(SecretShare);
+
+/// Feldman commitments `a_k B` to the coefficients `a_k` of a
+/// sharing polynomial.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeldmanCommitment {
+    /// Commitments to the coefficients, beginning with the secret
+    coefficients: Vec<RistrettoPoint>,
+}
+
+impl FeldmanCommitment {
+    const DESCRIPTION : &'static str = "Feldman commitments to the coefficients of a sharing polynomial";
+
+    /// Share `secret` among `participants`, any `threshold` of whom
+    /// may recover it, returning our commitments and shares indexed
+    /// from one.
+    ///
+    /// We panic unless `1 <= threshold <= participants`.
+    pub fn deal<R>(secret: &Scalar, threshold: u16, participants: u16, mut rng: R) -> (FeldmanCommitment, Vec<SecretShare>)
+    where R: RngCore+CryptoRng
+    {
+        assert!(1 <= threshold && threshold <= participants, "VSS requires 1 <= threshold <= participants.");
+
+        let mut coefficients = random_polynomial(*secret, threshold, &mut rng);
+        let commitment = FeldmanCommitment {
+            coefficients: coefficients.iter().map(|a| a * &constants::RISTRETTO_BASEPOINT_TABLE).collect(),
+        };
+        let shares = (1..=participants)
+            .map(|index| SecretShare { index, value: evaluate_polynomial(&coefficients, index) })
+            .collect();
+        coefficients.zeroize();
+        (commitment, shares)
+    }
+
+    /// Construct from commitments to the coefficients, beginning with
+    /// the secret, or return `None` unless between one and `u16::MAX`
+    /// are given.
+    pub fn new(coefficients: Vec<RistrettoPoint>) -> Option<FeldmanCommitment> {
+        if ! valid_coefficient_count(coefficients.len()) { return None; }
+        Some(FeldmanCommitment { coefficients })
+    }
+
+    /// Commitments to the coefficients, beginning with the secret
+    pub fn coefficients(&self) -> &[RistrettoPoint] { &self.coefficients }
+
+    /// Number of shares required to recover the secret
+    pub fn threshold(&self) -> u16 { self.coefficients.len() as u16 }
+
+    /// Public key of the shared secret
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_point(self.coefficients[0])
+    }
+
+    /// Public share of the participant with the given index, under
+    /// which their partial signatures verify.
+    pub fn public_share(&self, index: u16) -> PublicKey {
+        PublicKey::from_point(evaluate_commitments(&self.coefficients, index))
+    }
+
+    /// Check a share against our commitments.
+    pub fn verify_share(&self, share: &SecretShare) -> SignatureResult<()> {
+        if &share.value * &constants::RISTRETTO_BASEPOINT_TABLE == evaluate_commitments(&self.coefficients, share.index) {
+            Ok(())
+        } else {
+            Err(SignatureError::EquationFalse)
+        }
+    }
+
+    /// Convert to bytes, consisting of the compressed commitments.
+    pub fn to_bytes(&self) -> Vec<u8> { commitments_to_bytes(&self.coefficients) }
+
+    /// Construct from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<FeldmanCommitment> {
+        let coefficients = commitments_from_bytes("FeldmanCommitment", FeldmanCommitment::DESCRIPTION, bytes) ?;
+        Ok(FeldmanCommitment { coefficients })
+    }
+}
+
+serde_boilerplate!(FeldmanCommitment);
+
+
+// === Pedersen VSS === //
+
+/// One participant's share of a secret dealt by `PedersenCommitment::deal`.
+#[derive(Clone,Zeroize)]
+#[zeroize(drop)]
+pub struct PedersenShare {
+    /// Nonzero evaluation point of this share
+    index: u16,
+    /// Evaluation of the sharing polynomial at `index`
+    value: Scalar,
+    /// Evaluation of the blinding polynomial at `index`
+    blinding: Scalar,
+}
+
+impl core::fmt::Debug for PedersenShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PedersenShare( index: {} )", self.index)
+    }
+}
+
+impl PedersenShare {
+    const DESCRIPTION : &'static str = "A 66 byte blinded share of a secret scalar";
+
+    /// Index of this share, between one and the number of participants
+    pub fn index(&self) -> u16 { self.index }
+
+    /// Evaluation of the sharing polynomial at our index
+    pub fn value(&self) -> &Scalar { &self.value }
+
+    /// Evaluation of the blinding polynomial at our index
+    pub fn blinding(&self) -> &Scalar { &self.blinding }
+
+    /// Our unblinded `SecretShare`
+    pub fn secret_share(&self) -> SecretShare {
+        SecretShare { index: self.index, value: self.value }
+    }
+
+    /// Convert this share to bytes, consisting of the little endian
+    /// index followed by the value and blinding.
+    pub fn to_bytes(&self) -> [u8; PEDERSEN_SHARE_LENGTH] {
+        let mut bytes = [0u8; PEDERSEN_SHARE_LENGTH];
+        bytes[..2].copy_from_slice(&self.index.to_le_bytes());
+        bytes[2..34].copy_from_slice(self.value.as_bytes());
+        bytes[34..].copy_from_slice(self.blinding.as_bytes());
+        bytes
+    }
+
+    /// Construct a share from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<PedersenShare> {
+        if bytes.len() != PEDERSEN_SHARE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "PedersenShare",
+                description: PedersenShare::DESCRIPTION,
+                length: PEDERSEN_SHARE_LENGTH,
+            });
+        }
+        Ok(PedersenShare {
+            index: index_from_bytes(&bytes[..2]) ?,
+            value: scalar_from_bytes(&bytes[2..34]) ?,
+            blinding: scalar_from_bytes(&bytes[34..]) ?,
+        })
+    }
+}
+
+serde_boilerplate!(PedersenShare);
+
+/// Pedersen commitments `a_k B + b_k H` to the coefficients `a_k`
+/// of a sharing polynomial, blinded by the coefficients `b_k` of a
+/// second polynomial.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PedersenCommitment {
+    /// Blinded commitments to the coefficients, beginning with the secret
+    coefficients: Vec<RistrettoPoint>,
+}
+
+impl PedersenCommitment {
+    const DESCRIPTION : &'static str = "Pedersen commitments to the coefficients of a sharing polynomial";
+
+    /// Share `secret` among `participants`, any `threshold` of whom
+    /// may recover it, returning our commitments and shares indexed
+    /// from one.
+    ///
+    /// We panic unless `1 <= threshold <= participants`.
+    pub fn deal<R>(secret: &Scalar, threshold: u16, participants: u16, mut rng: R) -> (PedersenCommitment, Vec<PedersenShare>)
+    where R: RngCore+CryptoRng
+    {
+        assert!(1 <= threshold && threshold <= participants, "VSS requires 1 <= threshold <= participants.");
+
+        let h = pedersen_generator();
+        let mut coefficients = random_polynomial(*secret, threshold, &mut rng);
+        let blinding = random_scalar(&mut rng);
+        let mut blindings = random_polynomial(blinding, threshold, &mut rng);
+        let commitment = PedersenCommitment {
+            coefficients: coefficients.iter().zip(blindings.iter())
+                .map(|(a,b)| a * &constants::RISTRETTO_BASEPOINT_TABLE + b * h)
+                .collect(),
+        };
+        let shares = (1..=participants).map(|index| PedersenShare {
+            index,
+            value: evaluate_polynomial(&coefficients, index),
+            blinding: evaluate_polynomial(&blindings, index),
+        }).collect();
+        coefficients.zeroize();
+        blindings.zeroize();
+        (commitment, shares)
+    }
+
+    /// Construct from blinded commitments to the coefficients, beginning
+    /// with the secret, or return `None` unless between one and
+    /// `u16::MAX` are given.
+    pub fn new(coefficients: Vec<RistrettoPoint>) -> Option<PedersenCommitment> {
+        if ! valid_coefficient_count(coefficients.len()) { return None; }
+        Some(PedersenCommitment { coefficients })
+    }
+
+    /// Blinded commitments to the coefficients, beginning with the secret
+    pub fn coefficients(&self) -> &[RistrettoPoint] { &self.coefficients }
+
+    /// Number of shares required to recover the secret
+    pub fn threshold(&self) -> u16 { self.coefficients.len() as u16 }
+
+    /// Check a share against our commitments.
+    pub fn verify_share(&self, share: &PedersenShare) -> SignatureResult<()> {
+        let lhs = &share.value * &constants::RISTRETTO_BASEPOINT_TABLE + share.blinding * pedersen_generator();
+        if lhs == evaluate_commitments(&self.coefficients, share.index) {
+            Ok(())
+        } else {
+            Err(SignatureError::EquationFalse)
+        }
+    }
+
+    /// Convert to bytes, consisting of the compressed commitments.
+    pub fn to_bytes(&self) -> Vec<u8> { commitments_to_bytes(&self.coefficients) }
+
+    /// Construct from bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<PedersenCommitment> {
+        let coefficients = commitments_from_bytes("PedersenCommitment", PedersenCommitment::DESCRIPTION, bytes) ?;
+        Ok(PedersenCommitment { coefficients })
+    }
+}
+
+serde_boilerplate!(PedersenCommitment);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::signing_context;
+
+    #[test]
+    fn feldman_shares_and_public_shares() {
+        // #[cfg(feature = "getrandom")]
+        let csprng = rand_core::OsRng;
+
+        let secret = SecretKey::generate_with(csprng);
+        let (commitment, shares) = FeldmanCommitment::deal(&secret.key, 3, 5, csprng);
+        assert_eq!(commitment.threshold(), 3);
+        assert_eq!(commitment.public_key(), secret.to_public());
+        let commitment = FeldmanCommitment::from_bytes(&commitment.to_bytes()).unwrap();
+
+        // Thresholds must fit in a u16 without wrapping.
+        let oversized: Vec<RistrettoPoint> = (0..=u16::MAX as usize).map(|_| commitment.coefficients()[0]).collect();
+        assert!(FeldmanCommitment::from_bytes(&commitments_to_bytes(&oversized)).is_err());
+        assert!(FeldmanCommitment::new(oversized).is_none());
+
+        let ctx = signing_context(b"vss test");
+        for share in shares.iter() {
+            let share = SecretShare::from_bytes(&share.to_bytes()[..]).unwrap();
+            assert!(commitment.verify_share(&share).is_ok());
+            let public = commitment.public_share(share.index());
+            assert_eq!(public, share.public_key());
+            let keypair = share.to_secret_key(csprng).to_keypair();
+            let signature = keypair.sign(ctx.bytes(b"partial"));
+            assert!(public.verify(ctx.bytes(b"partial"), &signature).is_ok());
+        }
+
+        let mut bad = shares[1].clone();
+        bad.value += Scalar::one();
+        assert_eq!(commitment.verify_share(&bad), Err(SignatureError::EquationFalse));
+        bad.value -= Scalar::one();
+        bad.index = 4;
+        assert!(commitment.verify_share(&bad).is_err());
+    }
+
+    #[test]
+    fn pedersen_shares() {
+        // #[cfg(feature = "getrandom")]
+        let csprng = rand_core::OsRng;
+
+        let secret = SecretKey::generate_with(csprng);
+        let (commitment, shares) = PedersenCommitment::deal(&secret.key, 2, 4, csprng);
+        let commitment = PedersenCommitment::from_bytes(&commitment.to_bytes()).unwrap();
+        assert!(commitment.coefficients()[0] != secret.to_public().into_point());
+        for share in shares.iter() {
+            let share = PedersenShare::from_bytes(&share.to_bytes()[..]).unwrap();
+            assert!(commitment.verify_share(&share).is_ok());
+        }
+
+        let mut bad = shares[0].clone();
+        bad.blinding += Scalar::one();
+        assert!(commitment.verify_share(&bad).is_err());
+
+        // Any two shares interpolate the secret at zero.
+        let (s1, s3) = (shares[0].value, shares[2].value);
+        let recovered = s1 * Scalar::from(3u64) * Scalar::from(2u64).invert() - s3 * Scalar::from(2u64).invert();
+        assert_eq!(recovered, secret.key);
+    }
+}
@@ -0,0 +1,335 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Offline/online Schnorr signing with precomputed nonce pools
+//!
+//! We precompute nonces `r` along with their commitments `R = r B`
+//! offline, so that signing online requires only hashing the
+//! transcript and one scalar multiply-add.  With std, a
+//! `NoncePoolRefiller` refills a spare pool on a background thread.
+//!
+//! We derive pool nonces like `SigningTranscript::witness_scalar`
+//! does, from a transcript keyed by the public key, the secret key's
+//! nonce seed, and system randomness, but of course cannot include
+//! the message.  We therefore absorb each `R` into our transcript
+//! after creating it, so that even a failed random number generator
+//! never repeats a nonce within one pool.  We cannot however protect
+//! against a failed random number generator across pools, so unlike
+//! `SecretKey::sign` we depend upon system randomness for security.
+//!
+//! We pop each nonce exactly once, zeroize its slot immediately, and
+//! never reallocate our storage, so nonces never linger in freed
+//! memory.  We deliberately implement neither `Clone` nor serde for
+//! `NoncePool` because any copy risks nonce reuse, which reveals the
+//! secret key.
+
+use core::fmt::{Debug};
+
+use rand_core::{RngCore,CryptoRng};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto};
+use curve25519_dalek::scalar::Scalar;
+
+use merlin::Transcript;
+use zeroize::Zeroize;
+
+#[cfg(feature = "std")]
+use std::{sync::mpsc, thread};
+
+use super::*;
+use crate::context::SigningTranscript;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+
+/// Pool of precomputed signing nonces for one `Keypair`.
+///
+/// We never grow beyond the capacity allocated when creating the pool.
+/// Among pools for the same keypair, `append` moves nonces quickly,
+/// so a `NoncePoolRefiller` refills a spare pool on a background
+/// thread, from which the signing thread tops up the pool it signs with.
+pub struct NoncePool {
+    /// Public key for which we generate nonces
+    public: PublicKey,
+    /// Transcript keyed by the public key and all `R` produced so far
+    t: Transcript,
+    /// Nonce seed copied from the `SecretKey`
+    seed: [u8; 32],
+    /// Precomputed nonces `r` along with `R = r B`
+    nonces: Vec<(Scalar,CompressedRistretto)>,
+}
+
+impl Debug for NoncePool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NoncePool( public: {:?}, len: {} )", &self.public, self.nonces.len())
+    }
+}
+
+impl Drop for NoncePool {
+    fn drop(&mut self) {
+        for (r,_) in self.nonces.iter_mut() {
+            r.zeroize();
+        }
+        self.seed.zeroize();
+    }
+}
+
+impl NoncePool {
+    /// Create an empty pool for `keypair` holding at most `capacity` nonces.
+    pub fn new(keypair: &Keypair, capacity: usize) -> NoncePool {
+        let mut t = Transcript::new(b"NoncePool");
+        t.commit_point(b"pool:pk", keypair.public.as_compressed());
+        NoncePool {
+            public: keypair.public,
+            t,
+            seed: keypair.secret.nonce,
+            nonces: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Public key for which this pool holds nonces
+    pub fn public_key(&self) -> &PublicKey { &self.public }
+
+    /// Number of nonces available
+    pub fn len(&self) -> usize { self.nonces.len() }
+
+    /// Returns true if no nonces remain
+    pub fn is_empty(&self) -> bool { self.nonces.is_empty() }
+
+    /// Maximum number of nonces this pool holds
+    pub fn capacity(&self) -> usize { self.nonces.capacity() }
+
+    /// Fill this pool to capacity using system randomness.
+    pub fn refill(&mut self) {
+        self.refill_rng(super::rand_hack())
+    }
+
+    /// Fill this pool to capacity using the given random number generator.
+    #[allow(non_snake_case)]
+    pub fn refill_rng<R>(&mut self, mut rng: R)
+    where R: RngCore+CryptoRng
+    {
+        while self.nonces.len() < self.nonces.capacity() {
+            let mut scalar_bytes = [0u8; 64];
+            self.t.witness_bytes_rng(b"pool:nonce", &mut scalar_bytes, &[&self.seed], &mut rng);
+            let r = Scalar::from_bytes_mod_order_wide(&scalar_bytes);
+            scalar_bytes.zeroize();
+            let R = (&r * &constants::RISTRETTO_BASEPOINT_TABLE).compress();
+            self.t.commit_point(b"pool:R", &R);
+            self.nonces.push((r,R));
+        }
+    }
+
+    /// Move nonces from `other` into our spare capacity, leaving any
+    /// that do not fit in `other`.
+    ///
+    /// We panic if `other` was created for a different public key.
+    pub fn append(&mut self, other: &mut NoncePool) {
+        assert!(self.public == other.public, "NoncePools must belong to the same keypair.");
+        while self.nonces.len() < self.nonces.capacity() {
+            match other.pop() {
+                Some(nonce) => self.nonces.push(nonce),
+                None => break,
+            }
+        }
+    }
+
+    /// Remove one nonce, zeroizing its slot in our storage.
+    #[allow(non_snake_case)]
+    fn pop(&mut self) -> Option<(Scalar,CompressedRistretto)> {
+        let (r, R) = self.nonces.last_mut() ?;
+        let nonce = (*r, *R);
+        r.zeroize();
+        self.nonces.pop();
+        Some(nonce)
+    }
+}
+
+
+/// Background thread that keeps a spare `NoncePool` filled, from which
+/// the signing thread tops up the pool it signs with.
+#[cfg(feature = "std")]
+pub struct NoncePoolRefiller {
+    /// Public key for which we generate nonces
+    public: PublicKey,
+    /// Spare pools filled by our thread
+    filled: mpsc::Receiver<NoncePool>,
+    /// Spare pools returned to our thread for refilling
+    drained: Option<mpsc::Sender<NoncePool>>,
+    /// Our refilling thread
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+#[cfg(feature = "std")]
+impl Debug for NoncePoolRefiller {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NoncePoolRefiller( public: {:?} )", &self.public)
+    }
+}
+
+#[cfg(feature = "std")]
+impl NoncePoolRefiller {
+    /// Spawn a thread that keeps a spare pool of `capacity` nonces
+    /// for `keypair` filled using system randomness.
+    pub fn new(keypair: &Keypair, capacity: usize) -> NoncePoolRefiller {
+        let (drained, to_fill) = mpsc::channel::<NoncePool>();
+        let (filled_sender, filled) = mpsc::channel();
+        drained.send(NoncePool::new(keypair, capacity)).expect("Receiver exists.");
+        let thread = thread::spawn(move || {
+            for mut spare in to_fill {
+                spare.refill();
+                if filled_sender.send(spare).is_err() { break; }
+            }
+        });
+        NoncePoolRefiller { public: keypair.public, filled, drained: Some(drained), thread: Some(thread) }
+    }
+
+    /// Move nonces from our spare pool into `pool` if our thread has
+    /// filled it, without waiting, and return the number moved.
+    ///
+    /// We panic if `pool` belongs to another keypair.
+    pub fn try_top_up(&mut self, pool: &mut NoncePool) -> usize {
+        match self.filled.try_recv() {
+            Ok(spare) => self.top_up_from(pool, spare),
+            Err(_) => 0,
+        }
+    }
+
+    /// Move nonces from our spare pool into `pool`, waiting for our
+    /// thread to fill it, and return the number moved.
+    ///
+    /// We panic if `pool` belongs to another keypair.
+    pub fn top_up(&mut self, pool: &mut NoncePool) -> usize {
+        match self.filled.recv() {
+            Ok(spare) => self.top_up_from(pool, spare),
+            Err(_) => 0,
+        }
+    }
+
+    /// Append `spare` onto `pool` and return `spare` for refilling.
+    fn top_up_from(&mut self, pool: &mut NoncePool, mut spare: NoncePool) -> usize {
+        let before = pool.len();
+        pool.append(&mut spare);
+        if let Some(drained) = self.drained.as_ref() {
+            // Our thread only exits once we drop `drained`.
+            let _ = drained.send(spare);
+        }
+        pool.len() - before
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for NoncePoolRefiller {
+    fn drop(&mut self) {
+        // Closing our channel stops our thread once it finishes any refill.
+        self.drained = None;
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+
+impl Keypair {
+    /// Create a `NoncePool` for this keypair filled with `capacity`
+    /// nonces using system randomness.
+    pub fn nonce_pool(&self, capacity: usize) -> NoncePool {
+        let mut pool = NoncePool::new(self, capacity);
+        pool.refill();
+        pool
+    }
+
+    /// Spawn a `NoncePoolRefiller` for this keypair that keeps a spare
+    /// pool of `capacity` nonces filled on a background thread.
+    #[cfg(feature = "std")]
+    pub fn nonce_pool_refiller(&self, capacity: usize) -> NoncePoolRefiller {
+        NoncePoolRefiller::new(self, capacity)
+    }
+
+    /// Sign a transcript with this keypair using a nonce from `pool`.
+    ///
+    /// Produces the same signatures as `Keypair::sign`, except for the
+    /// choice of nonce, so `PublicKey::verify` accepts them.  We return
+    /// `None` if `pool` is empty, and panic if `pool` belongs to
+    /// another keypair.
+    #[allow(non_snake_case)]
+    pub fn sign_with_pool<T>(&self, mut t: T, pool: &mut NoncePool) -> Option<Signature>
+    where T: SigningTranscript
+    {
+        assert!(self.public == pool.public, "NoncePool must belong to the signing keypair.");
+        let (mut r, R) = pool.pop() ?;
+
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk",self.public.as_compressed());
+        t.commit_point(b"sign:R",&R);
+
+        let k: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
+        let s: Scalar = k * self.secret.key + r;
+
+        r.zeroize();
+
+        Some(Signature{ R, s })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_pool_sign_append() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let ctx = signing_context(b"offline/online");
+
+        let mut pool = keypair.nonce_pool(3);
+        assert_eq!(pool.len(), 3);
+        let mut sigs: Vec<Signature> = Vec::new();
+        while let Some(sig) = keypair.sign_with_pool(ctx.bytes(b"msg"), &mut pool) {
+            assert!( keypair.verify(ctx.bytes(b"msg"), &sig).is_ok(),
+                "Verification of a signature from a nonce pool failed!" );
+            assert!( keypair.verify(ctx.bytes(b"other"), &sig).is_err() );
+            assert!( sigs.iter().all(|other| other.R != sig.R), "Nonce pool reused a nonce!" );
+            sigs.push(sig);
+        }
+        assert_eq!(sigs.len(), 3);
+        assert!(pool.is_empty());
+
+        let mut fresh = NoncePool::new(&keypair, 5);
+        fresh.refill_rng(csprng);
+        pool.append(&mut fresh);
+        assert_eq!((pool.len(), fresh.len()), (3, 2));
+        let sig = keypair.sign_with_pool(ctx.bytes(b"msg"), &mut pool).unwrap();
+        assert!( keypair.verify(ctx.bytes(b"msg"), &sig).is_ok() );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn nonce_pool_background_refill() {
+        let keypair = Keypair::generate_with(rand_core::OsRng);
+        let ctx = signing_context(b"offline/online");
+
+        let mut pool = NoncePool::new(&keypair, 4);
+        let mut refiller = keypair.nonce_pool_refiller(3);
+        let mut seen: Vec<CompressedRistretto> = Vec::new();
+        for _ in 0..5 {
+            assert_eq!(refiller.top_up(&mut pool), 3);
+            while let Some(sig) = keypair.sign_with_pool(ctx.bytes(b"msg"), &mut pool) {
+                assert!( keypair.verify(ctx.bytes(b"msg"), &sig).is_ok() );
+                assert!( ! seen.contains(&sig.R), "Background refill reused a nonce!" );
+                seen.push(sig.R);
+            }
+        }
+        assert_eq!(seen.len(), 15);
+    }
+}
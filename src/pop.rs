@@ -0,0 +1,159 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Proofs of possession for public keys
+//!
+//! A `ProofOfPossession` shows knowledge of the secret key behind a
+//! `PublicKey`, which blocks rogue key attacks upon registration of
+//! validator or VRF keys.  We bind proofs to a context, which should
+//! identify the registering account, so that nobody can copy another
+//! account's key along with its proof.
+//!
+//! We derive our challenge from a merlin transcript with its own
+//! protocol label, never from a `SigningTranscript` supplied by the
+//! caller, so no proof of possession doubles as a `Signature` on any
+//! message, or vice versa.  We also leave the schnorrkel marker bit of
+//! `Signature` unset in our serialization, so proofs of possession
+//! never deserialize as `Signature`s.
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+
+use merlin::Transcript;
+
+use super::*;
+use crate::context::SigningTranscript;
+
+
+/// The length of a `ProofOfPossession`, in bytes.
+pub const PROOF_OF_POSSESSION_LENGTH: usize = 64;
+
+/// A Schnorr proof of knowledge of the secret key of a `PublicKey`.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct ProofOfPossession {
+    /// `R = r B` for the prover's nonce `r`
+    pub (crate) R: CompressedRistretto,
+    /// `s = r + c a` which satisfies `s B = R + c A`
+    pub (crate) s: Scalar,
+}
+
+impl core::fmt::Debug for ProofOfPossession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ProofOfPossession( R: {:?}, s: {:?} )", &self.R, &self.s)
+    }
+}
+
+impl ProofOfPossession {
+    const DESCRIPTION : &'static str = "A 64 byte Ristretto Schnorr proof of possession";
+
+    /// Convert this `ProofOfPossession` to a byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; PROOF_OF_POSSESSION_LENGTH] {
+        let mut bytes: [u8; PROOF_OF_POSSESSION_LENGTH] = [0u8; PROOF_OF_POSSESSION_LENGTH];
+        bytes[..32].copy_from_slice(&self.R.as_bytes()[..]);
+        bytes[32..].copy_from_slice(&self.s.as_bytes()[..]);
+        bytes
+    }
+
+    /// Construct a `ProofOfPossession` from a slice of bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<ProofOfPossession> {
+        if bytes.len() != PROOF_OF_POSSESSION_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "ProofOfPossession",
+                description: ProofOfPossession::DESCRIPTION,
+                length: PROOF_OF_POSSESSION_LENGTH
+            });
+        }
+
+        let mut lower: [u8; 32] = [0u8; 32];
+        let mut upper: [u8; 32] = [0u8; 32];
+        lower.copy_from_slice(&bytes[..32]);
+        upper.copy_from_slice(&bytes[32..]);
+
+        Ok(ProofOfPossession{ R: CompressedRistretto(lower), s: crate::sign::check_scalar(upper) ? })
+    }
+}
+
+serde_boilerplate!(ProofOfPossession);
+
+
+/// Transcript for proving or verifying possession of `public_key`.
+fn possession_transcript(ctx: &[u8], public_key: &PublicKey) -> Transcript {
+    let mut t = Transcript::new(b"SchnorrkelPoP");
+    t.append_message(b"ctx", ctx);
+    t.commit_point(b"pop:pk", public_key.as_compressed());
+    t
+}
+
+impl Keypair {
+    /// Prove possession of our secret key in the context `ctx`, which
+    /// should identify the account registering our public key.
+    #[allow(non_snake_case)]
+    pub fn prove_possession(&self, ctx: &[u8]) -> ProofOfPossession {
+        let mut t = possession_transcript(ctx, &self.public);
+
+        let mut r = t.witness_scalar(b"pop:nonce",&[&self.secret.nonce]);
+        let R = (&r * &constants::RISTRETTO_BASEPOINT_TABLE).compress();
+        t.commit_point(b"pop:R",&R);
+
+        let c: Scalar = t.challenge_scalar(b"pop:c");
+        let s: Scalar = c * self.secret.key + r;
+
+        zeroize::Zeroize::zeroize(&mut r);
+
+        ProofOfPossession{ R, s }
+    }
+}
+
+impl PublicKey {
+    /// Verify a proof of possession of the secret key for this
+    /// public key in the context `ctx`.
+    #[allow(non_snake_case)]
+    pub fn verify_possession(&self, ctx: &[u8], pop: &ProofOfPossession) -> SignatureResult<()> {
+        let A: &RistrettoPoint = self.as_point();
+
+        let mut t = possession_transcript(ctx, self);
+        t.commit_point(b"pop:R",&pop.R);
+
+        let c: Scalar = t.challenge_scalar(b"pop:c");
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, &(-A), &pop.s);
+
+        if R.compress() == pop.R { Ok(()) } else { Err(SignatureError::EquationFalse) }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prove_verify_possession() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let other = Keypair::generate_with(&mut csprng);
+
+        let pop = keypair.prove_possession(b"account alice");
+        let pop = ProofOfPossession::from_bytes(&pop.to_bytes()[..]).unwrap();
+        assert!( keypair.public.verify_possession(b"account alice", &pop).is_ok(),
+            "Verification of a valid proof of possession failed!" );
+        assert!( keypair.public.verify_possession(b"account mallory", &pop).is_err(),
+            "Verification of a proof of possession copied to another account passed!" );
+        assert!( other.public.verify_possession(b"account alice", &pop).is_err(),
+            "Verification of a proof of possession for another key passed!" );
+
+        assert!( Signature::from_bytes(&pop.to_bytes()[..]).is_err() );
+        let mut bytes = pop.to_bytes();
+        bytes[63] |= 128;
+        let sig = Signature::from_bytes(&bytes[..]).unwrap();
+        assert!( keypair.verify_simple(b"account alice", b"", &sig).is_err() );
+    }
+}
@@ -0,0 +1,342 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Proactive refresh and resharing of threshold keys
+//!
+//! We refresh every `FrostKeyShare` of a threshold group without
+//! changing its group `PublicKey`, so shares leaked before a refresh
+//! become useless when combined with shares leaked afterwards, following
+//! "Proactive Secret Sharing Or: How to Cope With Perpetual Leakage"
+//! by Amir Herzberg, Stanisław Jarecki, Hugo Krawczyk, and Moti Yung.
+//! We also reshare a group key to a new committee with a different
+//! threshold and number of participants, following "Redistributing
+//! Secret Shares to New Access Structures and Its Applications" by
+//! Yvo Desmedt and Sushil Jajodia.
+//!
+//! Both protocols run in one round, in which every dealer broadcasts
+//! a `FeldmanCommitment` from points.rs and sends every recipient
+//! their `SecretShare` privately:
+//!
+//! - In a refresh, every participant deals a sharing of zero, which
+//!   every participant adds to their existing share.
+//! - In a reshare, threshold many old participants each deal a sharing
+//!   of their share, whose commitments begin with their verification
+//!   share, and new participants interpolate these sharings.
+//!
+//! We check every dealing against the old `ThresholdPublicKey`, so
+//! the group `PublicKey` never changes, and every share against its
+//! dealing.  We handle no complaints however, so recipients must abort
+//! on any error, after which callers should rerun the protocol without
+//! the misbehaving dealer.  All recipients must use the same dealers.
+//!
+//! We report errors with the `MultiSignatureStage` of musig.rs, using
+//! `Commitment` for dealings and `Reveal` for shares.
+
+use std::{collections::btree_map::{BTreeMap, Entry}, vec::Vec};
+
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+
+use rand_core::{RngCore,CryptoRng};
+
+use zeroize::Zeroize;
+
+use super::*;
+use crate::errors::MultiSignatureStage;
+use crate::frost::{FrostKeyShare,ThresholdPublicKey,lagrange_coefficient};
+use crate::points::vss::{FeldmanCommitment,SecretShare,evaluate_commitments};
+
+
+impl FrostKeyShare {
+    /// Deal a sharing of zero to every participant in our group, for
+    /// refreshing their shares with `FrostKeyShare::refresh`.
+    pub fn refresh_dealing<R>(&self, rng: R) -> (FeldmanCommitment, Vec<SecretShare>)
+    where R: RngCore+CryptoRng
+    {
+        let participants = self.public.shares.len() as u16;
+        FeldmanCommitment::deal(&Scalar::zero(), self.public.threshold, participants, rng)
+    }
+
+    /// Deal a sharing of our share to a new committee of `participants`,
+    /// any `threshold` of whom may sign, for receiving with
+    /// `ThresholdPublicKey::reshare`.
+    ///
+    /// We panic unless `1 <= threshold <= participants`.
+    pub fn reshare_dealing<R>(&self, threshold: u16, participants: u16, rng: R) -> (FeldmanCommitment, Vec<SecretShare>)
+    where R: RngCore+CryptoRng
+    {
+        FeldmanCommitment::deal(&self.secret.key, threshold, participants, rng)
+    }
+
+    /// Begin refreshing our share by receiving dealings from
+    /// `FrostKeyShare::refresh_dealing`.
+    pub fn refresh(&self) -> Reshare {
+        let participants = self.public.shares.len() as u16;
+        Reshare {
+            old: self.public.clone(),
+            refreshing: Some(self.secret.key),
+            index: self.index,
+            threshold: self.public.threshold,
+            participants,
+            dealings: BTreeMap::new(),
+        }
+    }
+}
+
+impl ThresholdPublicKey {
+    /// Begin receiving a share of this group key as participant `index`
+    /// of a new committee of `participants`, any `threshold` of whom may
+    /// sign, from dealings by `FrostKeyShare::reshare_dealing`.
+    ///
+    /// We panic unless `1 <= index <= participants` and
+    /// `1 <= threshold <= participants`.
+    pub fn reshare(&self, index: u16, threshold: u16, participants: u16) -> Reshare {
+        assert!(1 <= index && index <= participants, "Resharing requires 1 <= index <= participants.");
+        assert!(1 <= threshold && threshold <= participants, "Resharing requires 1 <= threshold <= participants.");
+        Reshare {
+            old: self.clone(),
+            refreshing: None,
+            index,
+            threshold,
+            participants,
+            dealings: BTreeMap::new(),
+        }
+    }
+}
+
+/// Recipient state for refreshing or resharing a threshold key
+pub struct Reshare {
+    /// Public keys of the old group
+    old: ThresholdPublicKey,
+    /// Our old share if refreshing, or `None` if resharing
+    refreshing: Option<Scalar>,
+    /// Our nonzero index in the new group
+    index: u16,
+    /// Number of participants required to sign in the new group
+    threshold: u16,
+    /// Number of participants in the new group
+    participants: u16,
+    /// Dealers' commitments along with their shares for us, if received
+    dealings: BTreeMap<u16,(FeldmanCommitment,Option<Scalar>)>,
+}
+
+impl Drop for Reshare {
+    fn drop(&mut self) {
+        self.refreshing.zeroize();
+        for (_,share) in self.dealings.values_mut() {
+            share.zeroize();
+        }
+    }
+}
+
+impl Reshare {
+    /// Our nonzero index in the new group
+    pub fn index(&self) -> u16 { self.index }
+
+    /// Iterate over the indices of dealers whose commitments we hold.
+    pub fn dealers(&self) -> impl Iterator<Item=u16> + '_ {
+        self.dealings.keys().cloned()
+    }
+
+    /// Record the broadcast commitment of the old participant `from`.
+    ///
+    /// We return `MuSigAbsent` if `from` was not an old participant,
+    /// and `MuSigInconsistent` with `duplicate: false` if the commitment
+    /// does not preserve the group key or has the wrong threshold.
+    pub fn add_dealing(&mut self, from: u16, commitment: &FeldmanCommitment) -> SignatureResult<()> {
+        let musig_stage = MultiSignatureStage::Commitment;
+        let verification_share = self.old.verification_share(from)
+            .ok_or(SignatureError::MuSigAbsent { musig_stage, }) ?;
+        let constant = commitment.coefficients()[0];
+        let preserves_key = match self.refreshing {
+            Some(_) => constant == RistrettoPoint::identity(),
+            None => constant == *verification_share.as_point(),
+        };
+        if ! preserves_key || commitment.coefficients().len() != self.threshold as usize {
+            return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, });
+        }
+        match self.dealings.entry(from) {
+            Entry::Vacant(v) => { v.insert((commitment.clone(), None)); },
+            Entry::Occupied(o) =>
+                if o.get().0 != *commitment {
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Record our share from the dealer `from`.
+    ///
+    /// We return `MuSigAbsent` if we hold no commitment from `from`, and
+    /// `MuSigInconsistent` with `duplicate: false` if the share is not
+    /// ours or fails verification against their commitment.
+    pub fn add_share(&mut self, from: u16, share: &SecretShare) -> SignatureResult<()> {
+        let musig_stage = MultiSignatureStage::Reveal;
+        let (commitment, received) = match self.dealings.get_mut(&from) {
+            Some(dealing) => dealing,
+            None => {
+                let musig_stage = MultiSignatureStage::Commitment;
+                return Err(SignatureError::MuSigAbsent { musig_stage, });
+            },
+        };
+        if share.index() != self.index || commitment.verify_share(share).is_err() {
+            return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: false, });
+        }
+        match received {
+            None => *received = Some(*share.value()),
+            Some(value) =>
+                if value != share.value() {
+                    return Err(SignatureError::MuSigInconsistent { musig_stage, duplicate: true, });
+                },
+        }
+        Ok(())
+    }
+
+    /// Complete the refresh or reshare, yielding our new share of the
+    /// unchanged group key, with a fresh nonce seed from `rng`.
+    ///
+    /// We return `MuSigAbsent` if we lack a share from any dealer, or
+    /// if dealers number fewer than the old threshold.
+    pub fn finish<R>(mut self, mut rng: R) -> SignatureResult<FrostKeyShare>
+    where R: RngCore+CryptoRng
+    {
+        let musig_stage = MultiSignatureStage::Reveal;
+        if self.dealings.len() < self.old.threshold as usize
+            || self.dealings.values().any(|(_,share)| share.is_none())
+        {
+            return Err(SignatureError::MuSigAbsent { musig_stage, });
+        }
+
+        // Refreshes add sharings of zero, while reshares interpolate
+        // sharings of old shares at zero.
+        let dealers: Vec<u16> = self.dealings.keys().cloned().collect();
+        let weights: BTreeMap<u16,Scalar> = dealers.iter().map(|i| {
+            let weight = match self.refreshing {
+                Some(_) => Scalar::one(),
+                None => lagrange_coefficient(*i, dealers.iter().cloned()),
+            };
+            (*i, weight)
+        }).collect();
+
+        let mut key = self.refreshing.unwrap_or_else(Scalar::zero);
+        for (i,(_,share)) in self.dealings.iter() {
+            key += weights[i] * share.unwrap();
+        }
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        let secret = SecretKey { key, nonce };
+        key.zeroize();
+
+        let shares = (1..=self.participants).map(|m| {
+            let point: RistrettoPoint = self.dealings.iter()
+                .map(|(i,(commitment,_))| weights[i] * evaluate_commitments(commitment.coefficients(), m))
+                .sum();
+            let point = match self.refreshing {
+                Some(_) => point + self.old.shares[&m].as_point(),
+                None => point,
+            };
+            (m, PublicKey::from_point(point))
+        }).collect();
+        let public = ThresholdPublicKey { threshold: self.threshold, group: self.old.group, shares };
+        self.refreshing.zeroize();
+        Ok(FrostKeyShare { index: self.index, secret, public })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sign with the first threshold many shares using frost.rs.
+    fn threshold_sign(shares: &[FrostKeyShare], msg: &[u8]) -> Signature {
+        let t = signing_context(b"reshare test").bytes(msg);
+        let threshold = shares[0].threshold_public_key().threshold() as usize;
+        let signing = &shares[..threshold];
+        let mut commits: Vec<_> = signing.iter().map(|s| s.frost(t.clone())).collect();
+        let commitments: Vec<_> = commits.iter().map(|c| c.our_commitment()).collect();
+        for (s,r) in signing.iter().zip(commitments.iter()) {
+            for j in commits.iter_mut() {
+                j.add_their_commitment(s.index(), *r).unwrap();
+            }
+        }
+        let mut signs: Vec<_> = commits.into_iter().map(|c| c.sign_stage().unwrap()).collect();
+        let partials: Vec<_> = signs.iter().map(|s| s.our_partial_signature()).collect();
+        for (s,p) in signing.iter().zip(partials.iter()) {
+            for j in signs.iter_mut() {
+                j.add_their_partial_signature(s.index(), *p).unwrap();
+            }
+        }
+        signs[0].sign().unwrap()
+    }
+
+    /// Run one refresh or reshare round, with every dealer sending
+    /// every recipient their share.
+    fn run_round(dealers: &[&FrostKeyShare], mut recipients: Vec<Reshare>, threshold: u16) -> Vec<FrostKeyShare> {
+        let csprng = rand_core::OsRng;
+        let participants = recipients.len() as u16;
+        for d in dealers.iter() {
+            let (commitment, shares) = match recipients[0].refreshing {
+                Some(_) => d.refresh_dealing(csprng),
+                None => d.reshare_dealing(threshold, participants, csprng),
+            };
+            for (r, share) in recipients.iter_mut().zip(shares.iter()) {
+                r.add_dealing(d.index(), &commitment).unwrap();
+                r.add_share(d.index(), share).unwrap();
+            }
+        }
+        recipients.into_iter().map(|r| r.finish(csprng).unwrap()).collect()
+    }
+
+    #[test]
+    fn refresh_and_reshare_preserve_group_key() {
+        let csprng = rand_core::OsRng;
+        let keypair = Keypair::generate_with(csprng);
+        let shares = keypair.frost_shares(2, 3);
+        let ctx = signing_context(b"reshare test");
+
+        let signature = threshold_sign(&shares, b"old");
+        assert!(keypair.public.verify(ctx.bytes(b"old"), &signature).is_ok());
+
+        // Refresh among all three participants.
+        let recipients = shares.iter().map(|s| s.refresh()).collect();
+        let refreshed = run_round(&shares.iter().collect::<Vec<_>>(), recipients, 2);
+        assert!(refreshed[0].secret_key().key != shares[0].secret_key().key);
+        for s in refreshed.iter() {
+            assert_eq!(s.threshold_public_key().public_key(), &keypair.public);
+            assert_eq!(&s.secret_key().to_public(), s.threshold_public_key().verification_share(s.index()).unwrap());
+        }
+        let signature = threshold_sign(&refreshed, b"refreshed");
+        assert!(keypair.public.verify(ctx.bytes(b"refreshed"), &signature).is_ok());
+
+        // Reshare from two refreshed participants to a 3 of 5 committee.
+        let old = refreshed[0].threshold_public_key();
+        let recipients = (1..=5).map(|j| old.reshare(j, 3, 5)).collect();
+        let reshared = run_round(&[&refreshed[1], &refreshed[2]], recipients, 3);
+        for s in reshared.iter() {
+            assert_eq!(s.threshold_public_key().public_key(), &keypair.public);
+            assert_eq!(&s.secret_key().to_public(), s.threshold_public_key().verification_share(s.index()).unwrap());
+        }
+        let signature = threshold_sign(&reshared[2..], b"reshared");
+        assert!(keypair.public.verify(ctx.bytes(b"reshared"), &signature).is_ok());
+
+        // Dealings that change the group key, and bad shares, get rejected.
+        let mut r = old.reshare(1, 3, 5);
+        let (zero, _) = refreshed[0].refresh_dealing(csprng);
+        assert!(r.add_dealing(1, &zero).is_err());
+        let (commitment, bad) = refreshed[0].reshare_dealing(3, 5, csprng);
+        let mut coefficients = commitment.coefficients().to_vec();
+        coefficients.push(coefficients[1]);
+        let oversized = FeldmanCommitment::new(coefficients).unwrap();
+        assert_eq!( r.add_dealing(1, &oversized),
+            Err(SignatureError::MuSigInconsistent { musig_stage: MultiSignatureStage::Commitment, duplicate: false }) );
+        r.add_dealing(1, &commitment).unwrap();
+        assert!(r.add_share(1, &bad[1]).is_err());
+        assert!(r.add_share(2, &bad[0]).is_err());
+        assert!(r.finish(csprng).is_err());
+    }
+}
@@ -0,0 +1,461 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### 1-of-n ring signatures over schnorrkel `PublicKey`s
+//!
+//! We implement the Schnorr ring signatures from "1-out-of-n Signatures
+//! from a Variety of Keys" by Masayuki Abe, Miyako Ohkubo, and Koutarou
+//! Suzuki https://www.iacr.org/archive/asiacrypt2002/25010414/25010414.pdf
+//! which prove some member of a ring of public keys signed, without
+//! revealing which member.
+//!
+//! We commit the whole ring, in the order supplied, to the
+//! `SigningTranscript` before deriving any challenge, and commit the
+//! ring index alongside each `R` value, so ring signatures bind both
+//! the ring and its ordering.  Verifiers must therefore supply the
+//! ring in the same order as the signer.
+//!
+//! We also implement linkable ring signatures, following "Linkable
+//! Spontaneous Anonymous Group Signature for Ad Hoc Groups" by Joseph
+//! K. Liu, Victor K. Wei, and Duncan S. Wong
+//! https://eprint.iacr.org/2004/027 except our key image `I = a H`
+//! uses a base point `H` hashed from a linking context, not from the
+//! ring.  Any two linkable ring signatures by the same key with the
+//! same linking context thus share their `KeyImage`, while key images
+//! from different linking contexts remain unlinkable under DDH.
+//!
+//! Ring signatures grow linearly with the ring, so we require `alloc`.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec::Vec, collections::btree_map::{BTreeMap, Entry}};
+#[cfg(feature = "std")]
+use std::{vec::Vec, collections::btree_map::{BTreeMap, Entry}};
+
+use curve25519_dalek::constants;
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::VartimeMultiscalarMul;
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::points::{RistrettoBoth,RISTRETTO_POINT_LENGTH};
+
+
+/// An AOS ring signature "detached" from both the signed message and the ring.
+///
+/// We store the initial challenge `c_0` along with one response `s_i`
+/// for every public key in the ring, so these take `32 (n+1)` bytes
+/// for a ring of `n` public keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingSignature {
+    /// Initial challenge `c_0`
+    pub (crate) c: Scalar,
+    /// Responses `s_i`, one per ring member
+    pub (crate) s: Vec<Scalar>,
+}
+
+impl RingSignature {
+    const DESCRIPTION : &'static str = "A ring signature consisting of 32 (n+1) bytes for a ring of n Ristretto Schnorr public keys";
+
+    /// Number of ring members this ring signature covers.
+    pub fn ring_len(&self) -> usize { self.s.len() }
+
+    /// Convert this `RingSignature` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.s.len() + 1));
+        bytes.extend_from_slice(self.c.as_bytes());
+        for s in self.s.iter() {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        bytes
+    }
+
+    /// Construct a `RingSignature` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<RingSignature> {
+        if bytes.len() % 32 != 0 || bytes.len() < 64 {
+            return Err(SignatureError::BytesLengthError {
+                name: "RingSignature",
+                description: RingSignature::DESCRIPTION,
+                length: 0 // Variable length
+            });
+        }
+        let mut scalars = bytes.chunks(32).map(|b| {
+            let mut s = [0u8; 32];
+            s.copy_from_slice(b);
+            Scalar::from_canonical_bytes(s).ok_or(SignatureError::ScalarFormatError)
+        });
+        let c = scalars.next().expect("Length checked above; qed") ?;
+        let s = scalars.collect::<SignatureResult<Vec<Scalar>>>() ?;
+        Ok(RingSignature { c, s })
+    }
+}
+
+serde_boilerplate!(RingSignature);
+
+
+/// Commit the ring, in the order supplied, to the transcript.
+fn commit_ring<T: SigningTranscript>(t: &mut T, proto: &'static [u8], ring: &[PublicKey]) {
+    t.proto_name(proto);
+    t.commit_bytes(b"ring:n", &(ring.len() as u64).to_le_bytes());
+    for pk in ring {
+        t.commit_point(b"ring:pk", pk.as_compressed());
+    }
+}
+
+/// Compute the challenge `c_{i+1}` from the ring member index `i` and `R_i`.
+#[allow(non_snake_case)]
+fn ring_challenge<T>(t: &T, i: usize, R: &CompressedRistretto) -> Scalar
+where T: SigningTranscript+Clone
+{
+    let mut t = t.clone();
+    t.commit_bytes(b"ring:i", &(i as u64).to_le_bytes());
+    t.commit_point(b"ring:R", R);
+    t.challenge_scalar(b"ring:c")
+}
+
+impl Keypair {
+    /// Sign a transcript with this keypair as some anonymous member
+    /// of `ring`.
+    ///
+    /// We return `None` if `ring` does not contain our public key.
+    #[allow(non_snake_case)]
+    pub fn ring_sign<T>(&self, mut t: T, ring: &[PublicKey]) -> Option<RingSignature>
+    where T: SigningTranscript+Clone
+    {
+        let n = ring.len();
+        let j = ring.iter().position(|pk| *pk == self.public) ?;
+        commit_ring(&mut t, b"AOS-ring-sig", ring);
+
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        let mut s: Vec<Scalar> = Vec::with_capacity(n);
+        s.resize(n, Scalar::zero());
+        let mut cs = s.clone();
+
+        let mut r = t.witness_scalar(b"ring-signing",&[&self.secret.nonce]);
+        let R = (&r * B).compress();
+        let mut i = (j + 1) % n;
+        cs[i] = ring_challenge(&t, j, &R);
+        while i != j {
+            s[i] = t.witness_scalar(b"ring-response",&[&self.secret.nonce, &(i as u64).to_le_bytes()]);
+            let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&cs[i], ring[i].as_point(), &s[i]);
+            let next = (i + 1) % n;
+            cs[next] = ring_challenge(&t, i, &R.compress());
+            i = next;
+        }
+        s[j] = r - cs[j] * self.secret.key;
+
+        zeroize::Zeroize::zeroize(&mut r);
+
+        Some(RingSignature { c: cs[0], s })
+    }
+}
+
+/// Verify a ring signature on a transcript by some member of `ring`.
+///
+/// We require `ring` be supplied in the same order used by the signer.
+#[allow(non_snake_case)]
+pub fn ring_verify<T>(mut t: T, ring: &[PublicKey], signature: &RingSignature) -> SignatureResult<()>
+where T: SigningTranscript+Clone
+{
+    if ring.len() != signature.s.len() || ring.is_empty() {
+        return Err(SignatureError::EquationFalse);
+    }
+    commit_ring(&mut t, b"AOS-ring-sig", ring);
+
+    let mut c = signature.c;
+    for (i, (pk, s)) in ring.iter().zip(signature.s.iter()).enumerate() {
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, pk.as_point(), s);
+        c = ring_challenge(&t, i, &R.compress());
+    }
+
+    if c == signature.c { Ok(()) } else { Err(SignatureError::EquationFalse) }
+}
+
+
+// === Linkable ring signatures === //
+
+/// Hash the linking context to the base point `H` for key images.
+fn key_image_base(link_ctx: &[u8]) -> RistrettoPoint {
+    let mut t = merlin::Transcript::new(b"LSAG-key-image");
+    t.append_message(b"link-ctx", link_ctx);
+    let mut b = [0u8; 64];
+    t.challenge_bytes(b"base", &mut b);
+    RistrettoPoint::from_uniform_bytes(&b)
+}
+
+/// Key image `I = a H` of a secret key `a` in some linking context,
+/// where `H` denotes a point hashed from the linking context.
+///
+/// Linkable ring signatures by one key in one linking context always
+/// have the same `KeyImage`, so sorting or hashing these detects
+/// double votes or double spends.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyImage(RistrettoBoth);
+
+impl KeyImage {
+    const DESCRIPTION : &'static str = "A linkable ring signature key image represented as a 32-byte Ristretto compressed point";
+
+    /// Access the compressed Ristretto form
+    pub fn as_compressed(&self) -> &CompressedRistretto { self.0.as_compressed() }
+
+    /// Convert this `KeyImage` to a byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; RISTRETTO_POINT_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    /// Construct a `KeyImage` from a slice of bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<KeyImage> {
+        Ok(KeyImage(RistrettoBoth::from_bytes_ser("KeyImage",KeyImage::DESCRIPTION,bytes) ?))
+    }
+}
+
+serde_boilerplate!(KeyImage);
+
+/// A linkable ring signature "detached" from both the signed message
+/// and the ring, but which carries the signer's `KeyImage`.
+///
+/// We store the `KeyImage`, the initial challenge `c_0`, and one
+/// response `s_i` for every public key in the ring, so these take
+/// `32 (n+2)` bytes for a ring of `n` public keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkableRingSignature {
+    /// Signer's key image for the linking context
+    pub (crate) image: KeyImage,
+    /// Initial challenge `c_0`
+    pub (crate) c: Scalar,
+    /// Responses `s_i`, one per ring member
+    pub (crate) s: Vec<Scalar>,
+}
+
+impl LinkableRingSignature {
+    const DESCRIPTION : &'static str = "A linkable ring signature consisting of 32 (n+2) bytes for a ring of n Ristretto Schnorr public keys";
+
+    /// The signer's key image for the linking context.
+    pub fn key_image(&self) -> &KeyImage { &self.image }
+
+    /// Number of ring members this ring signature covers.
+    pub fn ring_len(&self) -> usize { self.s.len() }
+
+    /// Convert this `LinkableRingSignature` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.s.len() + 2));
+        bytes.extend_from_slice(self.image.as_compressed().as_bytes());
+        bytes.extend_from_slice(self.c.as_bytes());
+        for s in self.s.iter() {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        bytes
+    }
+
+    /// Construct a `LinkableRingSignature` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<LinkableRingSignature> {
+        if bytes.len() % 32 != 0 || bytes.len() < 96 {
+            return Err(SignatureError::BytesLengthError {
+                name: "LinkableRingSignature",
+                description: LinkableRingSignature::DESCRIPTION,
+                length: 0 // Variable length
+            });
+        }
+        let image = KeyImage::from_bytes(&bytes[..32]) ?;
+        let RingSignature { c, s } = RingSignature::from_bytes(&bytes[32..]) ?;
+        Ok(LinkableRingSignature { image, c, s })
+    }
+}
+
+serde_boilerplate!(LinkableRingSignature);
+
+/// Compute the challenge `c_{i+1}` from the ring member index `i`,
+/// `R_i = s_i B + c_i A_i`, and `R'_i = s_i H + c_i I`.
+#[allow(non_snake_case)]
+fn linkable_ring_challenge<T>(t: &T, i: usize, R: &RistrettoPoint, HR: &RistrettoPoint) -> Scalar
+where T: SigningTranscript+Clone
+{
+    let mut t = t.clone();
+    t.commit_bytes(b"ring:i", &(i as u64).to_le_bytes());
+    t.commit_point(b"ring:R", &R.compress());
+    t.commit_point(b"ring:HR", &HR.compress());
+    t.challenge_scalar(b"ring:c")
+}
+
+/// Commit the ring, the linking context, and the key image to the transcript.
+fn commit_linkable_ring<T>(t: &mut T, link_ctx: &[u8], ring: &[PublicKey], image: &KeyImage)
+where T: SigningTranscript
+{
+    commit_ring(t, b"LSAG-ring-sig", ring);
+    t.commit_bytes(b"ring:link-ctx", link_ctx);
+    t.commit_point(b"ring:key-image", image.as_compressed());
+}
+
+impl SecretKey {
+    /// Our `KeyImage` in the linking context `link_ctx`.
+    pub fn key_image(&self, link_ctx: &[u8]) -> KeyImage {
+        KeyImage(RistrettoBoth::from_point(self.key * key_image_base(link_ctx)))
+    }
+}
+
+impl Keypair {
+    /// Our `KeyImage` in the linking context `link_ctx`.
+    pub fn key_image(&self, link_ctx: &[u8]) -> KeyImage {
+        self.secret.key_image(link_ctx)
+    }
+
+    /// Sign a transcript with this keypair as some anonymous member
+    /// of `ring`, revealing our `KeyImage` in the linking context
+    /// `link_ctx`.
+    ///
+    /// We return `None` if `ring` does not contain our public key.
+    #[allow(non_snake_case)]
+    pub fn linkable_ring_sign<T>(&self, mut t: T, link_ctx: &[u8], ring: &[PublicKey])
+     -> Option<LinkableRingSignature>
+    where T: SigningTranscript+Clone
+    {
+        let n = ring.len();
+        let j = ring.iter().position(|pk| *pk == self.public) ?;
+        let H = key_image_base(link_ctx);
+        let image = KeyImage(RistrettoBoth::from_point(self.secret.key * H));
+        commit_linkable_ring(&mut t, link_ctx, ring, &image);
+
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        let I = image.0.as_point();
+        let mut s: Vec<Scalar> = Vec::with_capacity(n);
+        s.resize(n, Scalar::zero());
+        let mut cs = s.clone();
+
+        let mut r = t.witness_scalar(b"ring-signing",&[&self.secret.nonce]);
+        let mut i = (j + 1) % n;
+        cs[i] = linkable_ring_challenge(&t, j, &(&r * B), &(r * H));
+        while i != j {
+            s[i] = t.witness_scalar(b"ring-response",&[&self.secret.nonce, &(i as u64).to_le_bytes()]);
+            let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&cs[i], ring[i].as_point(), &s[i]);
+            let HR = s[i] * H + cs[i] * I;
+            let next = (i + 1) % n;
+            cs[next] = linkable_ring_challenge(&t, i, &R, &HR);
+            i = next;
+        }
+        s[j] = r - cs[j] * self.secret.key;
+
+        zeroize::Zeroize::zeroize(&mut r);
+
+        Some(LinkableRingSignature { image, c: cs[0], s })
+    }
+}
+
+/// Verify a linkable ring signature on a transcript by some member
+/// of `ring` in the linking context `link_ctx`.
+///
+/// We require `ring` be supplied in the same order used by the signer.
+/// After verification, callers should check the signature's `KeyImage`
+/// against those previously seen in this linking context, perhaps
+/// using `link_key_images`.
+#[allow(non_snake_case)]
+pub fn linkable_ring_verify<T>(
+    mut t: T,
+    link_ctx: &[u8],
+    ring: &[PublicKey],
+    signature: &LinkableRingSignature,
+) -> SignatureResult<()>
+where T: SigningTranscript+Clone
+{
+    if ring.len() != signature.s.len() || ring.is_empty() {
+        return Err(SignatureError::EquationFalse);
+    }
+    let H = key_image_base(link_ctx);
+    commit_linkable_ring(&mut t, link_ctx, ring, &signature.image);
+
+    let I = signature.image.0.as_point();
+    let mut c = signature.c;
+    for (i, (pk, s)) in ring.iter().zip(signature.s.iter()).enumerate() {
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, pk.as_point(), s);
+        let HR = RistrettoPoint::vartime_multiscalar_mul(&[*s, c], &[H, *I]);
+        c = linkable_ring_challenge(&t, i, &R, &HR);
+    }
+
+    if c == signature.c { Ok(()) } else { Err(SignatureError::EquationFalse) }
+}
+
+/// Batch link checker for key images from one linking context.
+///
+/// Returns `(i, j)` with `i < j` whenever the `j`th key image repeats
+/// the `i`th, with `i` always being the first occurrence, so every
+/// signature after the first by the same key appears exactly once.
+pub fn link_key_images<'a,I>(images: I) -> Vec<(usize, usize)>
+where I: IntoIterator<Item=&'a KeyImage>
+{
+    let mut seen = BTreeMap::new();
+    let mut links = Vec::new();
+    for (j, image) in images.into_iter().enumerate() {
+        match seen.entry(image) {
+            Entry::Vacant(v) => { v.insert(j); },
+            Entry::Occupied(o) => links.push((*o.get(), j)),
+        }
+    }
+    links
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_sign_verify() {
+        let keypairs: Vec<Keypair> = (0..7).map(|_| Keypair::generate()).collect();
+        let ring: Vec<PublicKey> = keypairs.iter().map(|k| k.public).collect();
+        let ctx = signing_context(b"governance vote");
+
+        for k in keypairs.iter() {
+            let sig = k.ring_sign(ctx.bytes(b"aye"), &ring).unwrap();
+            let sig = RingSignature::from_bytes(&sig.to_bytes()).unwrap();
+            assert_eq!(sig.ring_len(), ring.len());
+            assert!( ring_verify(ctx.bytes(b"aye"), &ring, &sig).is_ok(),
+                "Verification of a valid ring signature failed!" );
+            assert!( ring_verify(ctx.bytes(b"nay"), &ring, &sig).is_err(),
+                "Verification of a ring signature on a different message passed!" );
+            assert!( ring_verify(ctx.bytes(b"aye"), &ring[1..], &sig).is_err(),
+                "Verification of a ring signature with a different ring passed!" );
+        }
+
+        let mut reordered = ring.clone();
+        reordered.swap(0, 1);
+        let sig = keypairs[3].ring_sign(ctx.bytes(b"aye"), &ring).unwrap();
+        assert!( ring_verify(ctx.bytes(b"aye"), &reordered, &sig).is_err(),
+            "Verification of a ring signature with a reordered ring passed!" );
+
+        let outsider = Keypair::generate();
+        assert!( outsider.ring_sign(ctx.bytes(b"aye"), &ring).is_none() );
+    }
+
+
+    #[test]
+    fn linkable_ring_sign_verify_link() {
+        let keypairs: Vec<Keypair> = (0..5).map(|_| Keypair::generate()).collect();
+        let ring: Vec<PublicKey> = keypairs.iter().map(|k| k.public).collect();
+        let ctx = signing_context(b"governance vote");
+
+        let sigs: Vec<LinkableRingSignature> = [0, 2, 4, 2, 0, 0].iter()
+            .map(|i| keypairs[*i].linkable_ring_sign(ctx.bytes(b"aye"), b"poll 7", &ring).unwrap())
+            .collect();
+        for sig in sigs.iter() {
+            let sig = LinkableRingSignature::from_bytes(&sig.to_bytes()).unwrap();
+            assert!( linkable_ring_verify(ctx.bytes(b"aye"), b"poll 7", &ring, &sig).is_ok(),
+                "Verification of a valid linkable ring signature failed!" );
+            assert!( linkable_ring_verify(ctx.bytes(b"nay"), b"poll 7", &ring, &sig).is_err(),
+                "Verification of a linkable ring signature on a different message passed!" );
+            assert!( linkable_ring_verify(ctx.bytes(b"aye"), b"poll 8", &ring, &sig).is_err(),
+                "Verification of a linkable ring signature in a different linking context passed!" );
+        }
+        assert_eq!( *sigs[1].key_image(), keypairs[2].key_image(b"poll 7") );
+
+        let links = link_key_images(sigs.iter().map(|sig| sig.key_image()));
+        assert_eq!( links, vec![(1, 3), (0, 4), (0, 5)] );
+
+        let other = keypairs[0].linkable_ring_sign(ctx.bytes(b"aye"), b"poll 8", &ring).unwrap();
+        assert!( other.key_image() != sigs[0].key_image(),
+            "Key images from different linking contexts link!" );
+    }
+}
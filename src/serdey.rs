@@ -184,6 +184,36 @@ mod test {
         assert_eq!(serialized_size(&public_key).unwrap(), 32+8);  // Size specific to bincode==1.0.1
     }
 
+    #[test]
+    fn serialize_deserialize_half_aggregate_signature() {
+        let keypair = ED25519_SECRET_KEY.expand_to_keypair(ExpansionMode::Ed25519);
+        let ctx = signing_context(b"half aggregate serde");
+        let mut agg = HalfAggregateSignature::new();
+        for m in [b"a", b"b", b"c"].iter() {
+            agg.push(ctx.bytes(*m), &keypair.sign(ctx.bytes(*m)), &keypair.public);
+        }
+
+        let encoded: Vec<u8> = serialize(&agg).unwrap();
+        let decoded: HalfAggregateSignature = deserialize(&encoded).unwrap();
+        assert_eq!(agg, decoded);
+        let decoded: HalfAggregateSignature = from_value(to_value(&agg).unwrap()).unwrap();
+        assert_eq!(agg, decoded);
+
+        // Malformed merge trees and padding must fail without panicking.
+        let mut bytes = agg.to_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        assert!(deserialize::<HalfAggregateSignature>(&serialize(serde_bytes::Bytes::new(&bytes)).unwrap()).is_err());
+        bytes[last] ^= 0x81;
+        assert!(deserialize::<HalfAggregateSignature>(&serialize(serde_bytes::Bytes::new(&bytes)).unwrap()).is_err());
+        let mut empty = HalfAggregateSignature::new().to_bytes();
+        empty[4] = 1;
+        assert!(deserialize::<HalfAggregateSignature>(&serialize(serde_bytes::Bytes::new(&empty)).unwrap()).is_err());
+        let mut huge = agg.to_bytes();
+        huge[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(deserialize::<HalfAggregateSignature>(&serialize(serde_bytes::Bytes::new(&huge)).unwrap()).is_err());
+    }
+
     #[test]
     fn serialize_signature_size() {
         let signature: Signature = Signature::from_bytes(&SIGNATURE_BYTES).unwrap();
@@ -0,0 +1,259 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Shamir secret sharing of `MiniSecretKey`s for backups
+//!
+//! We split a `MiniSecretKey` into `n` `MiniSecretKeyShare`s, any
+//! `threshold` of which recover the `MiniSecretKey`, but fewer of
+//! which reveal nothing about it, so custodians may store backups
+//! without any one of them holding the seed.
+//!
+//! We share each byte of the seed separately over GF(2^8), using
+//! the AES field polynomial, like many existing secret sharing tools,
+//! because seeds exceed the Ristretto scalar field.  We implement
+//! field arithmetic without tables or branches on secret data.
+//!
+//! Every share carries a random identifier common to all shares from
+//! one split, its threshold, its index, and a checksum over all these,
+//! so `MiniSecretKey::combine` rejects shares that were corrupted or
+//! came from different splits.  We cannot however detect deliberately
+//! forged shares, which requires verifiable secret sharing.
+
+use rand_core::{RngCore,CryptoRng};
+
+use zeroize::Zeroize;
+
+use merlin::Transcript;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use super::*;
+
+
+/// The length of a `MiniSecretKeyShare`, in bytes.
+pub const MINI_SECRET_KEY_SHARE_LENGTH: usize = 4 + 1 + 1 + MINI_SECRET_KEY_LENGTH + 4;
+
+/// Multiply in GF(2^8) modulo the AES polynomial `x^8 + x^4 + x^3 + x + 1`.
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = a >> 7;
+        a <<= 1;
+        a ^= 0x1b & 0u8.wrapping_sub(carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Invert in GF(2^8) by computing `a^254`, which maps zero to zero.
+fn gf256_invert(a: u8) -> u8 {
+    let a2 = gf256_mul(a, a);
+    let a3 = gf256_mul(a2, a);
+    let a6 = gf256_mul(a3, a3);
+    let a12 = gf256_mul(a6, a6);
+    let a15 = gf256_mul(a12, a3);
+    let a30 = gf256_mul(a15, a15);
+    let a60 = gf256_mul(a30, a30);
+    let a120 = gf256_mul(a60, a60);
+    let a126 = gf256_mul(a120, a6);
+    let a127 = gf256_mul(a126, a);
+    gf256_mul(a127, a127)
+}
+
+/// One share of a `MiniSecretKey` split by `MiniSecretKey::split`.
+#[derive(Clone,Zeroize)]
+#[zeroize(drop)]
+pub struct MiniSecretKeyShare {
+    /// Random identifier common to all shares from one split
+    identifier: [u8; 4],
+    /// Number of shares required to recover the `MiniSecretKey`
+    threshold: u8,
+    /// Nonzero evaluation point of this share
+    index: u8,
+    /// Evaluations of the sharing polynomials at `index`, one per seed byte
+    value: [u8; MINI_SECRET_KEY_LENGTH],
+}
+
+impl core::fmt::Debug for MiniSecretKeyShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MiniSecretKeyShare( identifier: {:?}, threshold: {}, index: {} )",
+            &self.identifier, self.threshold, self.index)
+    }
+}
+
+impl MiniSecretKeyShare {
+    const DESCRIPTION : &'static str = "A 42 byte Shamir share of a MiniSecretKey";
+
+    /// Random identifier common to all shares from one split
+    pub fn identifier(&self) -> [u8; 4] { self.identifier }
+
+    /// Number of shares required to recover the `MiniSecretKey`
+    pub fn threshold(&self) -> u8 { self.threshold }
+
+    /// Index of this share, between one and the number of shares
+    pub fn index(&self) -> u8 { self.index }
+
+    /// Checksum over our identifier, threshold, index, and value
+    fn checksum(&self) -> [u8; 4] {
+        let mut t = Transcript::new(b"MiniSecretKeyShare");
+        t.append_message(b"identifier", &self.identifier);
+        t.append_message(b"threshold", &[self.threshold]);
+        t.append_message(b"index", &[self.index]);
+        t.append_message(b"value", &self.value);
+        let mut checksum = [0u8; 4];
+        t.challenge_bytes(b"checksum", &mut checksum);
+        checksum
+    }
+
+    /// Convert this share to bytes, consisting of the identifier,
+    /// threshold, index, value, and checksum.
+    pub fn to_bytes(&self) -> [u8; MINI_SECRET_KEY_SHARE_LENGTH] {
+        let mut bytes = [0u8; MINI_SECRET_KEY_SHARE_LENGTH];
+        bytes[..4].copy_from_slice(&self.identifier);
+        bytes[4] = self.threshold;
+        bytes[5] = self.index;
+        bytes[6..38].copy_from_slice(&self.value);
+        bytes[38..].copy_from_slice(&self.checksum());
+        bytes
+    }
+
+    /// Construct a share from bytes produced by `to_bytes`.
+    ///
+    /// We return `SecretShareInconsistent` if the checksum fails, or
+    /// if the threshold or index is zero.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<MiniSecretKeyShare> {
+        if bytes.len() != MINI_SECRET_KEY_SHARE_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "MiniSecretKeyShare",
+                description: MiniSecretKeyShare::DESCRIPTION,
+                length: MINI_SECRET_KEY_SHARE_LENGTH,
+            });
+        }
+        let mut share = MiniSecretKeyShare {
+            identifier: [0u8; 4],
+            threshold: bytes[4],
+            index: bytes[5],
+            value: [0u8; MINI_SECRET_KEY_LENGTH],
+        };
+        share.identifier.copy_from_slice(&bytes[..4]);
+        share.value.copy_from_slice(&bytes[6..38]);
+        if share.threshold == 0 || share.index == 0 || share.checksum()[..] != bytes[38..] {
+            return Err(SignatureError::SecretShareInconsistent);
+        }
+        Ok(share)
+    }
+}
+
+serde_boilerplate!(MiniSecretKeyShare);
+
+
+impl MiniSecretKey {
+    /// Split this `MiniSecretKey` into `n` shares, any `threshold` of
+    /// which recover it using `MiniSecretKey::combine`.
+    ///
+    /// We panic unless `1 <= threshold <= n`.
+    pub fn split<R>(&self, threshold: u8, n: u8, mut rng: R) -> Vec<MiniSecretKeyShare>
+    where R: RngCore+CryptoRng
+    {
+        assert!(1 <= threshold && threshold <= n, "Shamir sharing requires 1 <= threshold <= n.");
+
+        let mut identifier = [0u8; 4];
+        rng.fill_bytes(&mut identifier);
+
+        // Our coefficients for the seed byte `j` are `coefficients[k][j]`.
+        let mut coefficients: Vec<[u8; MINI_SECRET_KEY_LENGTH]> = Vec::with_capacity(threshold as usize);
+        coefficients.push(self.0);
+        while coefficients.len() < threshold as usize {
+            let mut c = [0u8; MINI_SECRET_KEY_LENGTH];
+            rng.fill_bytes(&mut c);
+            coefficients.push(c);
+        }
+
+        let shares = (1..=n).map(|index| {
+            let mut value = [0u8; MINI_SECRET_KEY_LENGTH];
+            for (j,v) in value.iter_mut().enumerate() {
+                *v = coefficients.iter().rev().fold(0u8, |acc, c| gf256_mul(acc, index) ^ c[j]);
+            }
+            MiniSecretKeyShare { identifier, threshold, index, value }
+        }).collect();
+        for c in coefficients.iter_mut() {
+            c.zeroize();
+        }
+        shares
+    }
+
+    /// Recover a `MiniSecretKey` from at least threshold many of its shares.
+    ///
+    /// We return `SecretShareInconsistent` if the shares disagree upon
+    /// their identifier or threshold, repeat an index, or number fewer
+    /// than their threshold.
+    pub fn combine(shares: &[MiniSecretKeyShare]) -> SignatureResult<MiniSecretKey> {
+        let first = shares.first().ok_or(SignatureError::SecretShareInconsistent) ?;
+        let threshold = first.threshold as usize;
+        if shares.len() < threshold
+            || shares.iter().any(|s| s.identifier != first.identifier || s.threshold != first.threshold)
+            || shares.iter().enumerate().any(|(i,s)| shares[..i].iter().any(|r| r.index == s.index))
+        {
+            return Err(SignatureError::SecretShareInconsistent);
+        }
+
+        let shares = &shares[..threshold];
+        let mut seed = [0u8; MINI_SECRET_KEY_LENGTH];
+        for s in shares.iter() {
+            // Lagrange coefficient for interpolating at zero, in which
+            // subtraction becomes xor.
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for r in shares.iter().filter(|r| r.index != s.index) {
+                numerator = gf256_mul(numerator, r.index);
+                denominator = gf256_mul(denominator, r.index ^ s.index);
+            }
+            let lambda = gf256_mul(numerator, gf256_invert(denominator));
+            for (b,v) in seed.iter_mut().zip(s.value.iter()) {
+                *b ^= gf256_mul(lambda, *v);
+            }
+        }
+        Ok(MiniSecretKey(seed))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_combine_mini_secret_key() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        for a in 1..=255u8 {
+            assert_eq!(gf256_mul(a, gf256_invert(a)), 1);
+        }
+
+        let seed = MiniSecretKey::generate_with(&mut csprng);
+        let shares = seed.split(3, 5, csprng);
+        let shares: Vec<MiniSecretKeyShare> = shares.iter()
+            .map(|s| MiniSecretKeyShare::from_bytes(&s.to_bytes()[..]).unwrap())
+            .collect();
+        assert!(MiniSecretKey::combine(&shares[..3]).unwrap() == seed);
+        assert!(MiniSecretKey::combine(&[shares[4].clone(), shares[1].clone(), shares[2].clone()]).unwrap() == seed);
+        assert!(MiniSecretKey::combine(&shares[..]).unwrap() == seed);
+
+        assert_eq!(MiniSecretKey::combine(&shares[..2]).unwrap_err(), SignatureError::SecretShareInconsistent);
+        assert!(MiniSecretKey::combine(&[shares[0].clone(), shares[0].clone(), shares[1].clone()]).is_err());
+        let other = seed.split(3, 5, csprng);
+        assert!(MiniSecretKey::combine(&[shares[0].clone(), shares[1].clone(), other[2].clone()]).is_err());
+
+        let mut bytes = shares[0].to_bytes();
+        bytes[10] ^= 1;
+        assert_eq!(MiniSecretKeyShare::from_bytes(&bytes[..]).unwrap_err(), SignatureError::SecretShareInconsistent);
+    }
+}
@@ -0,0 +1,795 @@
+// -*- mode: rust; -*-
+//
+// This file is part of schnorrkel.
+// Copyright (c) 2026 Web 3 Foundation
+// See LICENSE for licensing information.
+
+//! ### Sigma protocols for linear relations on Ristretto
+//!
+//! We prove knowledge of secret scalars `x_j` satisfying linear
+//! relations `P_k = sum_j x_j G_{k,j}` among public Ristretto points,
+//! of which the DLEQ proof `dleq_proove` in vrf.rs is one example.
+//! We compose these relations using AND and OR, the latter following
+//! "Proofs of Partial Knowledge and Simplified Design of Witness Hiding
+//! Protocols" by Ronald Cramer, Ivan Damgård, and Berry Schoenmakers.
+//! https://link.springer.com/chapter/10.1007/3-540-48658-5_19
+//!
+//! We apply Fiat-Shamir using any `SigningTranscript` and produce both
+//! a compact `SigmaProof` that holds the challenge `c`, and a larger
+//! `SigmaProofBatchable` that holds all commitments instead, exactly
+//! like `VRFProof` and `VRFProofBatchable`.  We use the same sign
+//! convention as sign.rs and vrf.rs, meaning responses satisfy
+//! `s = r - c x`.
+//!
+//! ```
+//! # #[cfg(feature = "getrandom")]
+//! # {
+//! use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+//! use curve25519_dalek::scalar::Scalar;
+//! use schnorrkel::{points::RistrettoBoth, signing_context};
+//! use schnorrkel::sigma::{LinearRelation,Statement,Witness};
+//!
+//! // Prove x B = A and x H = O for some secret x, like dleq_proove.
+//! let x = Scalar::from(1234567u64);  // Use a real secret here!
+//! let h = RISTRETTO_BASEPOINT_POINT * Scalar::from(7u64);
+//!
+//! let mut relation = LinearRelation::new();
+//! let var_x = relation.allocate_scalar();
+//! let var_b = relation.allocate_point(RistrettoBoth::from_point(RISTRETTO_BASEPOINT_POINT));
+//! let var_h = relation.allocate_point(RistrettoBoth::from_point(h));
+//! let var_a = relation.allocate_point(RistrettoBoth::from_point(x * RISTRETTO_BASEPOINT_POINT));
+//! let var_o = relation.allocate_point(RistrettoBoth::from_point(x * h));
+//! relation.constrain(var_a, &[(var_x, var_b)]);
+//! relation.constrain(var_o, &[(var_x, var_h)]);
+//! let statement = Statement::Relation(relation);
+//!
+//! let ctx = signing_context(b"example DLEQ");
+//! let (proof, proof_batchable) = statement.prove(ctx.bytes(b""), &Witness::Relation(vec![x]));
+//! assert!( statement.verify(ctx.bytes(b""), &proof).is_ok() );
+//! assert!( statement.verify_batchable(ctx.bytes(b""), &proof_batchable).is_ok() );
+//! # }
+//! ```
+
+use core::iter;
+
+use arrayref::array_ref;
+
+use curve25519_dalek::ristretto::{CompressedRistretto,RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::{IsIdentity,VartimeMultiscalarMul};
+
+use merlin::Transcript;
+use rand_core::RngCore;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{boxed::Box, vec::Vec};
+
+use super::*;
+use crate::context::SigningTranscript;
+use crate::points::RistrettoBoth;
+use crate::batch::rnd_128bit_scalar;
+
+
+// === Statements === //
+
+/// Secret scalar variable allocated by some `LinearRelation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarVar(usize);
+
+/// Public point variable allocated by some `LinearRelation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointVar(usize);
+
+/// Linear equation `lhs = sum_j x_j G_j` with each term being `(x_j, G_j)`.
+#[derive(Debug, Clone)]
+struct Equation {
+    lhs: PointVar,
+    rhs: Vec<(ScalarVar, PointVar)>,
+}
+
+/// Linear relations among secret scalars and public Ristretto points,
+/// all of which the prover satisfies simultaneously.
+#[derive(Debug, Clone, Default)]
+pub struct LinearRelation {
+    num_scalars: usize,
+    points: Vec<RistrettoBoth>,
+    equations: Vec<Equation>,
+}
+
+impl LinearRelation {
+    /// Create an empty linear relation.
+    pub fn new() -> LinearRelation { LinearRelation::default() }
+
+    /// Allocate a secret scalar variable.
+    pub fn allocate_scalar(&mut self) -> ScalarVar {
+        self.num_scalars += 1;
+        ScalarVar(self.num_scalars - 1)
+    }
+
+    /// Allocate a public point variable.
+    pub fn allocate_point(&mut self, point: RistrettoBoth) -> PointVar {
+        self.points.push(point);
+        PointVar(self.points.len() - 1)
+    }
+
+    /// Require that `lhs = sum_j x_j G_j` where `rhs` consists of
+    /// the pairs `(x_j, G_j)`.
+    ///
+    /// We panic if any variable was not allocated by this relation.
+    pub fn constrain(&mut self, lhs: PointVar, rhs: &[(ScalarVar, PointVar)]) {
+        const ASSERT_MESSAGE: &str = "Variable not allocated by this LinearRelation.";
+        assert!(lhs.0 < self.points.len(), "{}", ASSERT_MESSAGE);
+        for (x,g) in rhs {
+            assert!(x.0 < self.num_scalars && g.0 < self.points.len(), "{}", ASSERT_MESSAGE);
+        }
+        self.equations.push(Equation { lhs, rhs: rhs.to_vec() });
+    }
+
+    /// Number of secret scalars, and hence responses, in this relation.
+    pub fn num_scalars(&self) -> usize { self.num_scalars }
+
+    /// Number of equations, and hence commitments, in this relation.
+    pub fn num_equations(&self) -> usize { self.equations.len() }
+
+    fn point(&self, p: PointVar) -> &RistrettoPoint { self.points[p.0].as_point() }
+
+    /// Compute `c lhs + sum_j s_j G_j` for the given equation, which
+    /// yields our commitment `sum_j r_j G_j` when `s_j = r_j - c x_j`.
+    fn recompute(&self, eq: &Equation, c: &Scalar, s: &[Scalar]) -> RistrettoPoint {
+        RistrettoPoint::vartime_multiscalar_mul(
+            iter::once(c).chain(eq.rhs.iter().map(|(x,_)| &s[x.0])),
+            iter::once(self.point(eq.lhs)).chain(eq.rhs.iter().map(|(_,g)| self.point(*g))),
+        )
+    }
+}
+
+/// Composition of `LinearRelation`s using AND and OR.
+#[derive(Debug, Clone)]
+pub enum Statement {
+    /// Linear relations among secret scalars and public points
+    Relation(LinearRelation),
+    /// Proves all of these statements
+    And(Vec<Statement>),
+    /// Proves at least one of these statements, without revealing which
+    Or(Vec<Statement>),
+}
+
+/// Secret witness for a `Statement`, which must have the same shape.
+pub enum Witness {
+    /// Values of the scalar variables in a `LinearRelation`, in allocation order
+    Relation(Vec<Scalar>),
+    /// Witnesses for every statement in an AND
+    And(Vec<Witness>),
+    /// Index of the one statement we prove in an OR, along with its witness
+    Or(usize, Box<Witness>),
+}
+
+impl Drop for Witness {
+    fn drop(&mut self) {
+        if let Witness::Relation(x) = self {
+            zeroize::Zeroize::zeroize(x);
+        }
+    }
+}
+
+impl Statement {
+    /// Number of scalars in either proof encoding, meaning the
+    /// OR branch challenges followed by all responses.
+    pub fn num_proof_scalars(&self) -> usize {
+        self.num_challenges() + self.num_responses()
+    }
+
+    /// Number of commitments in a `SigmaProofBatchable`.
+    pub fn num_commitments(&self) -> usize {
+        match self {
+            Statement::Relation(r) => r.num_equations(),
+            Statement::And(cs) | Statement::Or(cs) => cs.iter().map(Statement::num_commitments).sum(),
+        }
+    }
+
+    /// Number of OR branch challenges, omitting the last one per OR.
+    fn num_challenges(&self) -> usize {
+        match self {
+            Statement::Relation(_) => 0,
+            Statement::And(cs) => cs.iter().map(Statement::num_challenges).sum(),
+            Statement::Or(cs) => cs.len().saturating_sub(1)
+                + cs.iter().map(Statement::num_challenges).sum::<usize>(),
+        }
+    }
+
+    fn num_responses(&self) -> usize {
+        match self {
+            Statement::Relation(r) => r.num_scalars(),
+            Statement::And(cs) | Statement::Or(cs) => cs.iter().map(Statement::num_responses).sum(),
+        }
+    }
+
+    /// Commit the full statement to the transcript.
+    fn commit<T: SigningTranscript>(&self, t: &mut T) {
+        let len = |l: usize| (l as u64).to_le_bytes();
+        match self {
+            Statement::Relation(r) => {
+                t.commit_bytes(b"sigma:relation", &len(r.num_scalars));
+                for p in r.points.iter() {
+                    t.commit_point(b"sigma:P", p.as_compressed());
+                }
+                for eq in r.equations.iter() {
+                    t.commit_bytes(b"sigma:lhs", &len(eq.lhs.0));
+                    for (x,g) in eq.rhs.iter() {
+                        t.commit_bytes(b"sigma:x", &len(x.0));
+                        t.commit_bytes(b"sigma:G", &len(g.0));
+                    }
+                }
+            },
+            Statement::And(cs) => {
+                t.commit_bytes(b"sigma:and", &len(cs.len()));
+                for c in cs.iter() { c.commit(t); }
+            },
+            Statement::Or(cs) => {
+                t.commit_bytes(b"sigma:or", &len(cs.len()));
+                for c in cs.iter() { c.commit(t); }
+            },
+        }
+    }
+
+    /// Visit every `LinearRelation` in order, along with its challenge
+    /// and responses, deriving the challenge of the last statement in
+    /// each OR from the others.
+    fn visit<'a,F>(&'a self, c: Scalar, challenges: &mut &[Scalar], responses: &mut &[Scalar], f: &mut F)
+     -> SignatureResult<()>
+    where F: FnMut(&'a LinearRelation, &Scalar, &[Scalar]) -> SignatureResult<()>
+    {
+        let take = |v: &mut &[Scalar], n: usize| -> SignatureResult<Vec<Scalar>> {
+            if v.len() < n { return Err(SignatureError::EquationFalse); }
+            let (head, tail) = v.split_at(n);
+            *v = tail;
+            Ok(head.to_vec())
+        };
+        match self {
+            Statement::Relation(r) => f(r, &c, &take(responses, r.num_scalars) ?),
+            Statement::And(cs) => cs.iter().try_for_each(|s| s.visit(c, challenges, responses, f)),
+            Statement::Or(cs) => {
+                if cs.is_empty() { return Err(SignatureError::EquationFalse); }
+                let mut cs_c = take(challenges, cs.len() - 1) ?;
+                cs_c.push(c - cs_c.iter().sum::<Scalar>());
+                cs.iter().zip(cs_c).try_for_each(|(s,c)| s.visit(c, challenges, responses, f))
+            },
+        }
+    }
+}
+
+
+// === Proofs === //
+
+/// Compact sigma protocol proof consisting of the challenge `c`
+/// followed by the proof scalars.
+///
+/// We cannot batch verify these, but they require only `32 (n+1)`
+/// bytes where `n = Statement::num_proof_scalars()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigmaProof {
+    /// Challenge
+    c: Scalar,
+    /// OR branch challenges followed by responses
+    scalars: Vec<Scalar>,
+}
+
+impl SigmaProof {
+    const DESCRIPTION : &'static str = "A sigma protocol proof consisting of a challenge scalar followed by 32 byte scalars";
+
+    /// Convert this `SigmaProof` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.scalars.len() + 1));
+        bytes.extend_from_slice(self.c.as_bytes());
+        for s in self.scalars.iter() {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        bytes
+    }
+
+    /// Construct a `SigmaProof` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<SigmaProof> {
+        if bytes.len() % 32 != 0 || bytes.is_empty() {
+            return Err(SignatureError::BytesLengthError {
+                name: "SigmaProof",
+                description: SigmaProof::DESCRIPTION,
+                length: 0 // Variable length
+            });
+        }
+        let mut scalars = scalars_from_bytes(bytes) ?;
+        let c = scalars.remove(0);
+        Ok(SigmaProof { c, scalars })
+    }
+}
+
+serde_boilerplate!(SigmaProof);
+
+/// Batchable sigma protocol proof consisting of all commitments
+/// followed by the proof scalars.
+///
+/// We encode these as a 4 byte little endian commitment count, the
+/// commitments, and then the proof scalars, so `4 + 32 (m+n)` bytes
+/// where `m = Statement::num_commitments()` and
+/// `n = Statement::num_proof_scalars()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigmaProofBatchable {
+    /// Commitments `sum_j r_j G_j`, one per equation
+    commitments: Vec<CompressedRistretto>,
+    /// OR branch challenges followed by responses
+    scalars: Vec<Scalar>,
+}
+
+impl SigmaProofBatchable {
+    const DESCRIPTION : &'static str = "A batchable sigma protocol proof consisting of a 4 byte commitment count followed by 32 byte Ristretto points and scalars";
+
+    /// Convert this `SigmaProofBatchable` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + 32 * (self.commitments.len() + self.scalars.len()));
+        bytes.extend_from_slice(&(self.commitments.len() as u32).to_le_bytes());
+        for p in self.commitments.iter() {
+            bytes.extend_from_slice(p.as_bytes());
+        }
+        for s in self.scalars.iter() {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        bytes
+    }
+
+    /// Construct a `SigmaProofBatchable` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<SigmaProofBatchable> {
+        let err = SignatureError::BytesLengthError {
+            name: "SigmaProofBatchable",
+            description: SigmaProofBatchable::DESCRIPTION,
+            length: 0 // Variable length
+        };
+        if bytes.len() < 4 || (bytes.len() - 4) % 32 != 0 {
+            return Err(err);
+        }
+        let mut m = [0u8; 4];
+        m.copy_from_slice(&bytes[..4]);
+        let m = u32::from_le_bytes(m) as usize;
+        let bytes = &bytes[4..];
+        if bytes.len() / 32 < m {
+            return Err(err);
+        }
+        let commitments = bytes[..32*m].chunks(32)
+            .map(|b| CompressedRistretto(*array_ref![b,0,32]))
+            .collect();
+        let scalars = scalars_from_bytes(&bytes[32*m..]) ?;
+        Ok(SigmaProofBatchable { commitments, scalars })
+    }
+
+    /// Compute the challenge and then the equations that verify this
+    /// proof, weighted by `z`, returned as scalars and points whose
+    /// multiscalar multiplication yields the identity.
+    #[allow(non_snake_case)]
+    fn weighted_equation<T,R>(&self, mut t: T, statement: &Statement, rng: &mut R, scalars: &mut Vec<Scalar>, points: &mut Vec<RistrettoPoint>)
+     -> SignatureResult<()>
+    where T: SigningTranscript, R: RngCore
+    {
+        if self.commitments.len() != statement.num_commitments()
+        || self.scalars.len() != statement.num_proof_scalars() {
+            return Err(SignatureError::EquationFalse);
+        }
+        t.proto_name(b"sigma-proof");
+        statement.commit(&mut t);
+        for p in self.commitments.iter() {
+            t.commit_point(b"sigma:R", p);
+        }
+        let c = t.challenge_scalar(b"sigma:c");
+
+        let (mut challenges, mut responses) = self.scalars.split_at(statement.num_challenges());
+        let mut commitments = self.commitments.iter();
+        statement.visit(c, &mut challenges, &mut responses, &mut |r,c,s| {
+            for eq in r.equations.iter() {
+                let z = rnd_128bit_scalar(rng);
+                let R = commitments.next().unwrap()
+                    .decompress().ok_or(SignatureError::PointDecompressionError) ?;
+                scalars.push(-z);
+                points.push(R);
+                scalars.push(z * c);
+                points.push(*r.point(eq.lhs));
+                for (x,g) in eq.rhs.iter() {
+                    scalars.push(z * s[x.0]);
+                    points.push(*r.point(*g));
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+serde_boilerplate!(SigmaProofBatchable);
+
+fn scalars_from_bytes(bytes: &[u8]) -> SignatureResult<Vec<Scalar>> {
+    bytes.chunks(32).map( |b|
+        Scalar::from_canonical_bytes(*array_ref![b,0,32])
+        .ok_or(SignatureError::ScalarFormatError)
+    ).collect()
+}
+
+
+// === Proving === //
+
+/// Prover state shared among all statements in one proof.
+struct Prover {
+    /// Transcript keyed by our witness seed, from which we derive nonces.
+    rng: Transcript,
+    commitments: Vec<RistrettoPoint>,
+    /// All OR branch challenges, including the last per OR.
+    challenges: Vec<Scalar>,
+    /// Responses, but initially nonces for statements we prove.
+    responses: Vec<Scalar>,
+}
+
+impl Prover {
+    fn witness_scalar(&mut self) -> Scalar {
+        let mut bytes = [0u8; 64];
+        self.rng.challenge_bytes(b"sigma:nonce", &mut bytes);
+        Scalar::from_bytes_mod_order_wide(&bytes)
+    }
+
+    /// Simulate a proof of `statement` with challenge `c`.
+    #[allow(non_snake_case)]
+    fn simulate(&mut self, statement: &Statement, c: Scalar) {
+        match statement {
+            Statement::Relation(r) => {
+                let start = self.responses.len();
+                for _ in 0..r.num_scalars {
+                    let s = self.witness_scalar();
+                    self.responses.push(s);
+                }
+                for eq in r.equations.iter() {
+                    let R = r.recompute(eq, &c, &self.responses[start..]);
+                    self.commitments.push(R);
+                }
+            },
+            Statement::And(cs) => for s in cs.iter() { self.simulate(s, c); },
+            Statement::Or(cs) => {
+                let start = self.challenges.len();
+                for _ in 1..cs.len() {
+                    let c = self.witness_scalar();
+                    self.challenges.push(c);
+                }
+                self.challenges.push(c - self.challenges[start..].iter().sum::<Scalar>());
+                for (i,s) in cs.iter().enumerate() {
+                    self.simulate(s, self.challenges[start + i]);
+                }
+            },
+        }
+    }
+
+    /// Commit to nonces for `statement`, simulating any OR branches
+    /// not selected by `witness`.
+    #[allow(non_snake_case)]
+    fn commit(&mut self, statement: &Statement, witness: &Witness) {
+        const ASSERT_MESSAGE: &str = "The Witness must have the same shape as the Statement.";
+        match (statement, witness) {
+            (Statement::Relation(r), Witness::Relation(x)) => {
+                assert!(r.num_scalars == x.len(), "{}", ASSERT_MESSAGE);
+                let start = self.responses.len();
+                for _ in 0..r.num_scalars {
+                    let n = self.witness_scalar();
+                    self.responses.push(n);
+                }
+                let nonces = &self.responses[start..];
+                for eq in r.equations.iter() {
+                    let R = eq.rhs.iter().map(|(x,g)| nonces[x.0] * r.point(*g)).sum();
+                    self.commitments.push(R);
+                }
+            },
+            (Statement::And(cs), Witness::And(ws)) => {
+                assert!(cs.len() == ws.len(), "{}", ASSERT_MESSAGE);
+                for (s,w) in cs.iter().zip(ws) { self.commit(s, w); }
+            },
+            (Statement::Or(cs), Witness::Or(j,w)) => {
+                assert!(*j < cs.len(), "{}", ASSERT_MESSAGE);
+                let start = self.challenges.len();
+                for i in 0..cs.len() {
+                    let c = if i == *j { Scalar::zero() } else { self.witness_scalar() };
+                    self.challenges.push(c);
+                }
+                for (i,s) in cs.iter().enumerate() {
+                    if i == *j { self.commit(s, w); }
+                    else { self.simulate(s, self.challenges[start + i]); }
+                }
+            },
+            _ => panic!("{}", ASSERT_MESSAGE),
+        }
+    }
+
+    /// Replace nonces by responses given the now known challenge `c`
+    /// for `statement`, or skip `statement` if we simulated it.
+    fn respond(&mut self, statement: &Statement, witness: Option<&Witness>, c: Scalar, cursor: &mut (usize, usize)) {
+        match (statement, witness) {
+            (Statement::Relation(r), Some(Witness::Relation(x))) => {
+                for (n,x) in self.responses[cursor.1..cursor.1 + r.num_scalars].iter_mut().zip(x) {
+                    *n -= c * x;
+                }
+                cursor.1 += r.num_scalars;
+            },
+            (Statement::Relation(r), _) => cursor.1 += r.num_scalars,
+            (Statement::And(cs), Some(Witness::And(ws))) => {
+                for (s,w) in cs.iter().zip(ws) { self.respond(s, Some(w), c, cursor); }
+            },
+            (Statement::Or(cs), Some(Witness::Or(j,w))) => {
+                let start = cursor.0;
+                cursor.0 += cs.len();
+                let others: Scalar = self.challenges[start..cursor.0].iter().sum();
+                self.challenges[start + j] = c - others;
+                for (i,s) in cs.iter().enumerate() {
+                    let w = if i == *j { Some(&**w) } else { None };
+                    self.respond(s, w, self.challenges[start + i], cursor);
+                }
+            },
+            (Statement::And(cs), _) => {
+                for s in cs.iter() { self.respond(s, None, c, cursor); }
+            },
+            (Statement::Or(cs), _) => {
+                let start = cursor.0;
+                cursor.0 += cs.len();
+                for (i,s) in cs.iter().enumerate() {
+                    self.respond(s, None, self.challenges[start + i], cursor);
+                }
+            },
+        }
+    }
+
+    /// Drop the last challenge from each OR, which verifiers derive.
+    fn compact_challenges(&self, statement: &Statement, cursor: &mut usize, out: &mut Vec<Scalar>) {
+        match statement {
+            Statement::Relation(_) => {},
+            Statement::And(cs) => for s in cs.iter() { self.compact_challenges(s, cursor, out); },
+            Statement::Or(cs) => {
+                out.extend_from_slice(&self.challenges[*cursor..*cursor + cs.len() - 1]);
+                *cursor += cs.len();
+                for s in cs.iter() { self.compact_challenges(s, cursor, out); }
+            },
+        }
+    }
+}
+
+impl Drop for Prover {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.responses);
+    }
+}
+
+impl Statement {
+    /// Prove this statement using `witness`, returning both compact
+    /// and batchable proofs, like `Keypair::dleq_proove` does.
+    ///
+    /// We panic if `witness` does not have the same shape as `self`,
+    /// but we do not check that `witness` satisfies `self`, so invalid
+    /// witnesses simply yield proofs that fail verification.
+    #[allow(non_snake_case)]
+    pub fn prove<T>(&self, mut t: T, witness: &Witness) -> (SigmaProof, SigmaProofBatchable)
+    where T: SigningTranscript
+    {
+        t.proto_name(b"sigma-proof");
+        self.commit(&mut t);
+
+        // We derive all nonces from the transcript and every witness
+        // scalar, along with system randomness.
+        fn seeds<'b>(w: &'b Witness, out: &mut Vec<&'b [u8]>) {
+            match w {
+                Witness::Relation(x) => out.extend(x.iter().map(|x| &x.as_bytes()[..])),
+                Witness::And(ws) => for w in ws.iter() { seeds(w, out); },
+                Witness::Or(_,w) => seeds(w, out),
+            }
+        }
+        let mut nonce_seeds = Vec::new();
+        seeds(witness, &mut nonce_seeds);
+        let mut seed = [0u8; 32];
+        t.witness_bytes(b"sigma-proving", &mut seed, &nonce_seeds);
+
+        let mut rng = Transcript::new(b"sigma-proving");
+        rng.append_message(b"seed", &seed);
+        zeroize::Zeroize::zeroize(&mut seed);
+
+        let mut p = Prover {
+            rng, commitments: Vec::new(), challenges: Vec::new(), responses: Vec::new(),
+        };
+        p.commit(self, witness);
+        let commitments: Vec<CompressedRistretto> = p.commitments.iter().map(|R| R.compress()).collect();
+
+        for R in commitments.iter() {
+            t.commit_point(b"sigma:R", R);
+        }
+        let c = t.challenge_scalar(b"sigma:c");
+        p.respond(self, Some(witness), c, &mut (0,0));
+
+        let mut scalars = Vec::with_capacity(self.num_proof_scalars());
+        p.compact_challenges(self, &mut 0, &mut scalars);
+        scalars.extend_from_slice(&p.responses);
+
+        (SigmaProof { c, scalars: scalars.clone() }, SigmaProofBatchable { commitments, scalars })
+    }
+}
+
+/// Verification of compact and batchable proofs.
+impl Statement {
+    /// Verify a compact proof of this statement, returning the
+    /// corresponding batchable proof like `PublicKey::dleq_verify` does.
+    #[allow(non_snake_case)]
+    pub fn verify<T>(&self, mut t: T, proof: &SigmaProof) -> SignatureResult<SigmaProofBatchable>
+    where T: SigningTranscript
+    {
+        if proof.scalars.len() != self.num_proof_scalars() {
+            return Err(SignatureError::EquationFalse);
+        }
+        t.proto_name(b"sigma-proof");
+        self.commit(&mut t);
+
+        let mut commitments = Vec::with_capacity(self.num_commitments());
+        let (mut challenges, mut responses) = proof.scalars.split_at(self.num_challenges());
+        self.visit(proof.c, &mut challenges, &mut responses, &mut |r,c,s| {
+            for eq in r.equations.iter() {
+                commitments.push(r.recompute(eq, c, s).compress());
+            }
+            Ok(())
+        }) ?;
+
+        for R in commitments.iter() {
+            t.commit_point(b"sigma:R", R);
+        }
+        if t.challenge_scalar(b"sigma:c") != proof.c {
+            return Err(SignatureError::EquationFalse);
+        }
+        Ok(SigmaProofBatchable { commitments, scalars: proof.scalars.clone() })
+    }
+
+    /// Verify a batchable proof of this statement.
+    pub fn verify_batchable<T>(&self, t: T, proof: &SigmaProofBatchable) -> SignatureResult<()>
+    where T: SigningTranscript
+    {
+        verify_batch(Some(t), core::slice::from_ref(self), core::slice::from_ref(proof))
+    }
+}
+
+/// Batch verify batchable sigma protocol proofs of the given statements.
+///
+/// We combine every equation from every proof using random 128-bit
+/// weights into one multiscalar multiplication, much like
+/// `dleq_verify_batch` does.
+#[allow(non_snake_case)]
+pub fn verify_batch<T,I>(
+    transcripts: I,
+    statements: &[Statement],
+    proofs: &[SigmaProofBatchable],
+) -> SignatureResult<()>
+where
+    T: SigningTranscript,
+    I: IntoIterator<Item=T>,
+{
+    const ASSERT_MESSAGE: &str = "The number of messages/transcripts, statements, and proofs must be equal.";
+    assert!(statements.len() == proofs.len(), "{}", ASSERT_MESSAGE);
+
+    // Use a random number generator keyed by the statements, the
+    // proofs, and the system random number generator.
+    let mut csprng = {
+        let mut t = Transcript::new(b"VS-RNG");
+        for (s,p) in statements.iter().zip(proofs) {
+            s.commit(&mut t);
+            for R in p.commitments.iter() {
+                t.commit_point(b"", R);
+            }
+        }
+        t.build_rng().finalize(&mut rand_hack())
+    };
+
+    let mut scalars = Vec::new();
+    let mut points = Vec::new();
+    let mut count = 0;
+    for (t,(s,p)) in transcripts.into_iter().zip(statements.iter().zip(proofs)) {
+        p.weighted_equation(t, s, &mut csprng, &mut scalars, &mut points) ?;
+        count += 1;
+    }
+    assert!(count == proofs.len(), "{}", ASSERT_MESSAGE);
+
+    if RistrettoPoint::vartime_multiscalar_mul(scalars, points).is_identity() {
+        Ok(())
+    } else {
+        Err(SignatureError::EquationFalse)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+
+    use super::*;
+
+    fn random_scalar() -> Scalar {
+        Scalar::random(&mut rand_core::OsRng)
+    }
+
+    /// Statement that we know the discrete logarithm of `a` base `B`.
+    fn dlog(a: &RistrettoPoint) -> Statement {
+        let mut r = LinearRelation::new();
+        let x = r.allocate_scalar();
+        let b = r.allocate_point(RistrettoBoth::from_point(RISTRETTO_BASEPOINT_POINT));
+        let a = r.allocate_point(RistrettoBoth::from_point(*a));
+        r.constrain(a, &[(x, b)]);
+        Statement::Relation(r)
+    }
+
+    #[test]
+    fn sigma_dleq_and_pedersen() {
+        let x = random_scalar();
+        let y = random_scalar();
+        let h = RISTRETTO_BASEPOINT_POINT * random_scalar();
+
+        // x B = A, x H = O, and x B + y H = C
+        let mut r = LinearRelation::new();
+        let vx = r.allocate_scalar();
+        let vy = r.allocate_scalar();
+        let vb = r.allocate_point(RistrettoBoth::from_point(RISTRETTO_BASEPOINT_POINT));
+        let vh = r.allocate_point(RistrettoBoth::from_point(h));
+        let va = r.allocate_point(RistrettoBoth::from_point(x * RISTRETTO_BASEPOINT_POINT));
+        let vo = r.allocate_point(RistrettoBoth::from_point(x * h));
+        let vc = r.allocate_point(RistrettoBoth::from_point(x * RISTRETTO_BASEPOINT_POINT + y * h));
+        r.constrain(va, &[(vx, vb)]);
+        r.constrain(vo, &[(vx, vh)]);
+        r.constrain(vc, &[(vx, vb), (vy, vh)]);
+        let statement = Statement::And(vec![ Statement::Relation(r), dlog(&(y * RISTRETTO_BASEPOINT_POINT)) ]);
+        let witness = Witness::And(vec![ Witness::Relation(vec![x, y]), Witness::Relation(vec![y]) ]);
+
+        let ctx = signing_context(b"sigma");
+        let (proof, proof_batchable) = statement.prove(ctx.bytes(b"hello"), &witness);
+        let proof = SigmaProof::from_bytes(&proof.to_bytes()).unwrap();
+        let proof_batchable = SigmaProofBatchable::from_bytes(&proof_batchable.to_bytes()).unwrap();
+
+        assert_eq!( statement.verify(ctx.bytes(b"hello"), &proof).unwrap(), proof_batchable );
+        assert!( statement.verify_batchable(ctx.bytes(b"hello"), &proof_batchable).is_ok(),
+            "Verification of a valid batchable sigma proof failed!" );
+        assert!( statement.verify(ctx.bytes(b"goodbye"), &proof).is_err(),
+            "Verification of a sigma proof on a different message passed!" );
+        assert!( statement.verify_batchable(ctx.bytes(b"goodbye"), &proof_batchable).is_err(),
+            "Verification of a batchable sigma proof on a different message passed!" );
+
+        let bad_witness = Witness::And(vec![ Witness::Relation(vec![x, x]), Witness::Relation(vec![y]) ]);
+        let (bad, bad_batchable) = statement.prove(ctx.bytes(b"hello"), &bad_witness);
+        assert!( statement.verify(ctx.bytes(b"hello"), &bad).is_err() );
+        assert!( statement.verify_batchable(ctx.bytes(b"hello"), &bad_batchable).is_err() );
+    }
+
+    #[test]
+    fn sigma_or_batch() {
+        let xs: Vec<Scalar> = (0..3).map(|_| random_scalar()).collect();
+        let points: Vec<RistrettoPoint> = xs.iter().map(|x| x * RISTRETTO_BASEPOINT_POINT).collect();
+        let unknown = RISTRETTO_BASEPOINT_POINT * random_scalar();
+
+        // (A_0 AND A_1) OR (U OR A_2), proved via A_2, and then via A_0 AND A_1.
+        let statement = Statement::Or(vec![
+            Statement::And(vec![ dlog(&points[0]), dlog(&points[1]) ]),
+            Statement::Or(vec![ dlog(&unknown), dlog(&points[2]) ]),
+        ]);
+        let witnesses = [
+            Witness::Or(1, Box::new(Witness::Or(1, Box::new(Witness::Relation(vec![xs[2]]))))),
+            Witness::Or(0, Box::new(Witness::And(vec![
+                Witness::Relation(vec![xs[0]]), Witness::Relation(vec![xs[1]]),
+            ]))),
+        ];
+
+        let ctx = signing_context(b"sigma");
+        let mut proofs = Vec::new();
+        for w in witnesses.iter() {
+            let (proof, proof_batchable) = statement.prove(ctx.bytes(b"or"), w);
+            assert!( statement.verify(ctx.bytes(b"or"), &proof).is_ok(),
+                "Verification of a valid OR sigma proof failed!" );
+            proofs.push(proof_batchable);
+        }
+        let statements = [statement.clone(), statement.clone()];
+        let transcripts = || ::std::iter::once(ctx.bytes(b"or")).cycle().take(2);
+        assert!( verify_batch(transcripts(), &statements, &proofs).is_ok(),
+            "Batch verification of valid OR sigma proofs failed!" );
+
+        proofs[1].scalars[0] += Scalar::one();
+        assert!( verify_batch(transcripts(), &statements, &proofs).is_err(),
+            "Batch verification of an altered OR sigma proof passed!" );
+    }
+}
@@ -216,6 +216,17 @@ impl SecretKey {
         PublicKey::from_bytes(& public_key.to_bytes()) ?
         .verify_simple(ctx,msg,&sig).map(|()| sig)
     }
+
+    /// Sign a message read from `reader` with this `SecretKey`,
+    /// prehashing as described in `SigningContext::streaming`.
+    #[cfg(feature = "std")]
+    pub fn sign_reader<R: std::io::Read>(&self, ctx: &SigningContext, mut reader: R, public_key: &PublicKey)
+     -> std::io::Result<Signature>
+    {
+        let mut stream = ctx.streaming();
+        std::io::copy(&mut reader, &mut stream) ?;
+        Ok(self.sign(stream.finalize(),public_key))
+    }
 }
 
 
@@ -226,17 +237,26 @@ impl PublicKey {
     /// `SigningContext` and a message, as well as the signature
     /// to be verified.
     #[allow(non_snake_case)]
-    pub fn verify<T: SigningTranscript>(&self, mut t: T, signature: &Signature)
+    pub fn verify<T: SigningTranscript>(&self, t: T, signature: &Signature)
      -> SignatureResult<()>
     {
         let A: &RistrettoPoint = self.as_point();
+        self.verify_with(t, signature, |k,s| RistrettoPoint::vartime_double_scalar_mul_basepoint(k, &(-A), s))
+    }
 
+    /// Verify a signature by this public key on a transcript, using
+    /// `mul` to compute `s B - k A` from `k` and `s`.
+    #[allow(non_snake_case)]
+    fn verify_with<T,F>(&self, mut t: T, signature: &Signature, mul: F)
+     -> SignatureResult<()>
+    where T: SigningTranscript, F: FnOnce(&Scalar,&Scalar) -> RistrettoPoint
+    {
         t.proto_name(b"Schnorr-sig");
         t.commit_point(b"sign:pk",self.as_compressed());
         t.commit_point(b"sign:R",&signature.R);
 
         let k: Scalar = t.challenge_scalar(b"sign:c");  // context, message, A/public_key, R=rG
-        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&k, &(-A), &signature.s);
+        let R = mul(&k, &signature.s);
 
         if R.compress() == signature.R { Ok(()) } else { Err(SignatureError::EquationFalse) }
     }
@@ -249,6 +269,20 @@ impl PublicKey {
         self.verify(t,signature)
     }
 
+    /// Verify a signature by this public key on a message read from
+    /// `reader`, prehashing as described in `SigningContext::streaming`.
+    ///
+    /// We return any I/O error in the outer `Result`, and the
+    /// signature verification result in the inner one.
+    #[cfg(feature = "std")]
+    pub fn verify_reader<R: std::io::Read>(&self, ctx: &SigningContext, mut reader: R, signature: &Signature)
+     -> std::io::Result<SignatureResult<()>>
+    {
+        let mut stream = ctx.streaming();
+        std::io::copy(&mut reader, &mut stream) ?;
+        Ok(self.verify(stream.finalize(),signature))
+    }
+
     /// A temporary verification routine for use in transitioning substrate testnets only.
     #[cfg(feature = "preaudit_deprecated")]
     #[allow(non_snake_case)]
@@ -388,6 +422,24 @@ impl Keypair {
         self.public.verify_simple(ctx, msg, signature)
     }
 
+    /// Sign a message read from `reader` with this keypair's secret key,
+    /// prehashing as described in `SigningContext::streaming`.
+    #[cfg(feature = "std")]
+    pub fn sign_reader<R: std::io::Read>(&self, ctx: &SigningContext, reader: R)
+     -> std::io::Result<Signature>
+    {
+        self.secret.sign_reader(ctx, reader, &self.public)
+    }
+
+    /// Verify a signature by keypair's public key on a message read
+    /// from `reader`, prehashing as described in `SigningContext::streaming`.
+    #[cfg(feature = "std")]
+    pub fn verify_reader<R: std::io::Read>(&self, ctx: &SigningContext, reader: R, signature: &Signature)
+     -> std::io::Result<SignatureResult<()>>
+    {
+        self.public.verify_reader(ctx, reader, signature)
+    }
+
 
     /// Sign a message with this `SecretKey`, but doublecheck the result.
     pub fn sign_doublecheck<T>(&self, t: T) -> SignatureResult<Signature>
@@ -412,6 +464,24 @@ impl Keypair {
 
 }
 
+impl PreparedPublicKey {
+    /// Verify a signature by this public key on a transcript, like
+    /// `PublicKey::verify` but using our precomputed tables.
+    pub fn verify<T: SigningTranscript>(&self, t: T, signature: &Signature)
+     -> SignatureResult<()>
+    {
+        self.public_key().verify_with(t, signature, |k,s| self.mul_basepoint_and_key(s, &(-k)))
+    }
+
+    /// Verify a signature by this public key on a message.
+    pub fn verify_simple(&self, ctx: &[u8], msg: &[u8], signature: &Signature)
+     -> SignatureResult<()>
+    {
+        let t = SigningContext::new(ctx).bytes(msg);
+        self.verify(t,signature)
+    }
+}
+
 
 #[cfg(test)]
 mod test {
@@ -451,6 +521,28 @@ mod test {
                 "Verification of a signature on a different message passed!");
     }
 
+    #[test]
+    fn sign_verify_prepared() {
+        let ctx = signing_context(b"good");
+
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let other = Keypair::generate_with(&mut csprng);
+        let prepared = PreparedPublicKey::new(&keypair.public);
+
+        for msg in [&b"test message"[..], b"another message"].iter() {
+            let sig = keypair.sign(ctx.bytes(msg));
+            assert!(prepared.verify(ctx.bytes(msg), &sig).is_ok(),
+                    "Verification of a valid signature with a prepared key failed!");
+            assert!(prepared.verify(ctx.bytes(b"wrong message"), &sig).is_err(),
+                    "Verification of a signature on a different message passed!");
+            assert!(prepared.verify(ctx.bytes(msg), &other.sign(ctx.bytes(msg))).is_err(),
+                    "Verification of a signature by a different key passed!");
+        }
+    }
+
     #[test]
     fn sign_verify_xof() {
         let good_sig: Signature;
@@ -487,6 +579,35 @@ mod test {
                 "Verification of a signature on a different message passed!");
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn sign_verify_reader() {
+        use sha2::Sha512;
+        use std::io::Write;
+        use std::vec::Vec;
+
+        let ctx = signing_context(b"testing testing 1 2 3");
+        let good: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+        let bad: &[u8] = b"wrong message";
+
+        let keypair = Keypair::generate();
+        let good_sig = keypair.sign_reader(&ctx, &good[..]).unwrap();
+
+        assert!(keypair.verify_reader(&ctx, &good[..], &good_sig).unwrap().is_ok(),
+                "Verification of a valid signature failed!");
+        assert!(keypair.verify_reader(&ctx, bad, &good_sig).unwrap().is_err(),
+                "Verification of a signature on a different message passed!");
+        assert!(keypair.verify(ctx.hash512(Sha512::default().chain(&good)), &good_sig).is_ok(),
+                "Streaming prehash disagrees with hash512 over SHA-512!");
+
+        let mut stream = ctx.streaming();
+        for chunk in good.chunks(777) {
+            stream.write_all(chunk).unwrap();
+        }
+        assert!(keypair.verify(stream.finalize(), &good_sig).is_ok(),
+                "Verification of a valid signature on a chunked message failed!");
+    }
+
     #[cfg(feature = "preaudit_deprecated")]
     #[test]
     fn can_verify_know_preaudit_deprecated_message() {
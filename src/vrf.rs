@@ -487,9 +487,9 @@ impl PublicKey {
 #[derive(Debug, Clone, PartialEq, Eq)] // PartialOrd, Ord, Hash
 pub struct VRFProof {
     /// Challenge
-    c: Scalar,
+    pub(crate) c: Scalar,
     /// Schnorr proof
-    s: Scalar,
+    pub(crate) s: Scalar,
 }
 
 impl VRFProof {
@@ -535,11 +535,11 @@ serde_boilerplate!(VRFProof);
 #[allow(non_snake_case)]
 pub struct VRFProofBatchable {
     /// Our nonce R = r G to permit batching the first verification equation
-    R: CompressedRistretto,
+    pub(crate) R: CompressedRistretto,
     /// Our input hashed and raised to r to permit batching the second verification equation
-    Hr: CompressedRistretto,
+    pub(crate) Hr: CompressedRistretto,
     /// Schnorr proof
-    s: Scalar,
+    pub(crate) s: Scalar,
 }
 
 impl VRFProofBatchable {
@@ -766,14 +766,35 @@ impl PublicKey {
     /// version exploits the slightly faster basepoint arithmetic.
     #[allow(non_snake_case)]
     pub fn dleq_verify<T>(
+        &self,
+        t: T,
+        p: &VRFInOut,
+        proof: &VRFProof,
+        kusama: bool,
+    ) -> SignatureResult<VRFProofBatchable>
+    where
+        T: SigningTranscript,
+    {
+        // let R = (&proof.c * self.as_point()) + (&proof.s * &constants::RISTRETTO_BASEPOINT_TABLE);
+        self.dleq_verify_with(t, p, proof, kusama, |c,s| {
+            RistrettoPoint::vartime_double_scalar_mul_basepoint(c, self.as_point(), s)
+        })
+    }
+
+    /// Verify DLEQ proof like `dleq_verify`, using `mul` to compute
+    /// `c A + s B` from `c` and `s`.
+    #[allow(non_snake_case)]
+    fn dleq_verify_with<T,F>(
         &self,
         mut t: T,
         p: &VRFInOut,
         proof: &VRFProof,
         kusama: bool,
+        mul: F,
     ) -> SignatureResult<VRFProofBatchable>
     where
         T: SigningTranscript,
+        F: FnOnce(&Scalar,&Scalar) -> RistrettoPoint,
     {
         t.proto_name(b"DLEQProof");
         // t.commit_point(b"vrf:g",constants::RISTRETTO_BASEPOINT_TABLE.basepoint().compress());
@@ -781,12 +802,7 @@ impl PublicKey {
         if !kusama {  t.commit_point(b"vrf:pk", self.as_compressed());  }
 
         // We recompute R aka u from the proof
-        // let R = (&proof.c * self.as_point()) + (&proof.s * &constants::RISTRETTO_BASEPOINT_TABLE);
-        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(
-            &proof.c,
-            self.as_point(),
-            &proof.s,
-        ).compress();
+        let R = mul(&proof.c, &proof.s).compress();
         t.commit_point(b"vrf:R=g^r", &R);
 
         // We also recompute h^r aka u using the proof
@@ -888,6 +904,51 @@ impl PublicKey {
     }
 }
 
+impl PreparedPublicKey {
+    /// Verify DLEQ proof like `PublicKey::dleq_verify`, but using
+    /// our precomputed tables.
+    pub fn dleq_verify<T>(
+        &self,
+        t: T,
+        p: &VRFInOut,
+        proof: &VRFProof,
+        kusama: bool,
+    ) -> SignatureResult<VRFProofBatchable>
+    where
+        T: SigningTranscript,
+    {
+        self.public_key().dleq_verify_with(t, p, proof, kusama, |c,s| {
+            self.mul_basepoint_and_key(s, c)
+        })
+    }
+
+    /// Verify VRF proof for one single input transcript and corresponding output.
+    pub fn vrf_verify<T: VRFSigningTranscript>(
+        &self,
+        t: T,
+        out: &VRFPreOut,
+        proof: &VRFProof,
+    ) -> SignatureResult<(VRFInOut, VRFProofBatchable)> {
+        self.vrf_verify_extra(t,out,proof,Transcript::new(b"VRF"))
+    }
+
+    /// Verify VRF proof for one single input transcript and corresponding output.
+    pub fn vrf_verify_extra<T,E>(
+        &self,
+        t: T,
+        out: &VRFPreOut,
+        proof: &VRFProof,
+        extra: E,
+    ) -> SignatureResult<(VRFInOut, VRFProofBatchable)>
+    where T: VRFSigningTranscript,
+          E: SigningTranscript,
+    {
+        let p = out.attach_input_hash(self.public_key(),t)?;
+        let proof_batchable = self.dleq_verify(extra, &p, proof, KUSAMA_VRF)?;
+        Ok((p, proof_batchable))
+    }
+}
+
 /// Batch verify DLEQ proofs where the public keys were held by
 /// different parties.
 ///
@@ -912,6 +973,32 @@ pub fn dleq_verify_batch(
     public_keys: &[PublicKey],
     kusama: bool,
 ) -> SignatureResult<()> {
+    dleq_verify_batch_with(ps, proofs, public_keys, kusama, false)
+}
+
+/// Batch verify DLEQ proofs like `dleq_verify_batch`, except we split
+/// the work across rayon's thread pool.
+#[cfg(feature = "rayon")]
+pub fn dleq_verify_batch_parallel(
+    ps: &[VRFInOut],
+    proofs: &[VRFProofBatchable],
+    public_keys: &[PublicKey],
+    kusama: bool,
+) -> SignatureResult<()> {
+    dleq_verify_batch_with(ps, proofs, public_keys, kusama, true)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[allow(non_snake_case)]
+fn dleq_verify_batch_with(
+    ps: &[VRFInOut],
+    proofs: &[VRFProofBatchable],
+    public_keys: &[PublicKey],
+    kusama: bool,
+    parallel: bool,
+) -> SignatureResult<()> {
+    use crate::batch::{maybe_par_map,multiscalar_is_identity};
+
     const ASSERT_MESSAGE: &'static str = "The number of messages/transcripts / input points, output points, proofs, and public keys must be equal.";
     assert!(ps.len() == proofs.len(), "{}", ASSERT_MESSAGE);
     assert!(proofs.len() == public_keys.len(), "{}", ASSERT_MESSAGE);
@@ -945,29 +1032,38 @@ pub fn dleq_verify_batch(
     let B_coefficient: Scalar = z_s.iter().sum();
 
     let t0 = Transcript::new(b"VRF");
-    let z_c: Vec<Scalar> = zz.iter().enumerate()
-        .map( |(i, z)| z * proofs[i].shorten_dleq(t0.clone(), &public_keys[i], &ps[i], kusama).c )
-        .collect();
+    let cs = maybe_par_map(
+        (0..proofs.len()).collect(),
+        |i| proofs[i].shorten_dleq(t0.clone(), &public_keys[i], &ps[i], kusama).c,
+        parallel,
+    );
+    let z_c: Vec<Scalar> = zz.iter().zip(cs).map(|(z, c)| z * c).collect();
 
     // Compute (∑ z[i] s[i] (mod l)) B + ∑ (z[i] c[i] (mod l)) A[i] - ∑ z[i] R[i] = 0
-    let mut b = RistrettoPoint::optional_multiscalar_mul(
+    let mut b = multiscalar_is_identity(
         zz.iter().map(|z| -z)
             .chain(z_c.iter().cloned())
-            .chain(once(B_coefficient)),
+            .chain(once(B_coefficient))
+            .collect(),
         proofs.iter().map(|proof| proof.R.decompress())
             .chain(public_keys.iter().map(|pk| Some(*pk.as_point())))
-            .chain(once(Some(constants::RISTRETTO_BASEPOINT_POINT))),
-    ).map(|id| id.is_identity()).unwrap_or(false);
+            .chain(once(Some(constants::RISTRETTO_BASEPOINT_POINT)))
+            .collect(),
+        parallel,
+    );
 
     // Compute (∑ z[i] s[i] (mod l)) Input[i] + ∑ (z[i] c[i] (mod l)) Output[i] - ∑ z[i] Hr[i] = 0
-    b &= RistrettoPoint::optional_multiscalar_mul(
+    b &= multiscalar_is_identity(
         zz.iter().map(|z| -z)
             .chain(z_c)
-            .chain(z_s),
+            .chain(z_s)
+            .collect(),
         proofs.iter().map(|proof| proof.Hr.decompress())
             .chain(ps.iter().map(|p| Some(*p.output.as_point())))
-            .chain(ps.iter().map(|p| Some(*p.input.as_point()))),
-    ).map(|id| id.is_identity()).unwrap_or(false);
+            .chain(ps.iter().map(|p| Some(*p.input.as_point())))
+            .collect(),
+        parallel,
+    );
 
     if b { Ok(()) } else { Err(SignatureError::EquationFalse) }
 }
@@ -1004,6 +1100,40 @@ where
     }
 }
 
+/// Batch verify VRFs by different signers like `vrf_verify_batch`,
+/// except we split the work across rayon's thread pool, which requires
+/// our transcripts be `Send`.
+#[cfg(feature = "rayon")]
+pub fn vrf_verify_batch_parallel<T, I>(
+    transcripts: I,
+    outs: &[VRFPreOut],
+    proofs: &[VRFProofBatchable],
+    publickeys: &[PublicKey],
+) -> SignatureResult<Box<[VRFInOut]>>
+where
+    T: VRFSigningTranscript+Send,
+    I: IntoIterator<Item = T>,
+{
+    let mut ts = transcripts.into_iter();
+    let items: Vec<_> = ts.by_ref()
+        .zip(publickeys)
+        .zip(outs)
+        .collect();
+    assert!(ts.next().is_none(), "Too few VRF outputs for VRF inputs.");
+    let ps = crate::batch::maybe_par_map(items, |((t, pk), out)| out.attach_input_hash(pk,t), true)
+        .into_iter()
+        .collect::<SignatureResult<Vec<VRFInOut>>>()?;
+    assert!(
+        ps.len() == outs.len(),
+        "Too few VRF inputs for VRF outputs."
+    );
+    if dleq_verify_batch_parallel(&ps[..], proofs, publickeys, KUSAMA_VRF).is_ok() {
+        Ok(ps.into_boxed_slice())
+    } else {
+        Err(SignatureError::EquationFalse)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "alloc")]
@@ -1059,6 +1189,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn vrf_verify_prepared() {
+        // #[cfg(feature = "getrandom")]
+        let mut csprng = rand_core::OsRng;
+
+        let keypair = Keypair::generate_with(&mut csprng);
+        let prepared = PreparedPublicKey::from(keypair.public);
+
+        let ctx = signing_context(b"yo!");
+        let (io, proof, proof_batchable) = keypair.vrf_sign(ctx.bytes(b"meow"));
+        let out = io.to_preout();
+        assert_eq!(
+            prepared.vrf_verify(ctx.bytes(b"meow"), &out, &proof).unwrap(),
+            keypair.public.vrf_verify(ctx.bytes(b"meow"), &out, &proof).unwrap(),
+            "Prepared VRF verification differs from unprepared"
+        );
+        assert_eq!(prepared.vrf_verify(ctx.bytes(b"meow"), &out, &proof).unwrap().1, proof_batchable);
+        assert!(
+            prepared.vrf_verify(ctx.bytes(b"woof"), &out, &proof).is_err(),
+            "VRF verification with incorrect message passed!"
+        );
+    }
+
     #[test]
     fn vrf_malleable() {
         // #[cfg(feature = "getrandom")]
@@ -1221,4 +1374,34 @@ mod tests {
             "Batch verification with incorrect points passed!"
         );
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn vrf_verify_batch_parallel_matches_single_threaded() {
+
+        let mut csprng = rand_core::OsRng;
+        let ctx = signing_context(b"yo!");
+        let messages: Vec<[u8; 8]> = (0..200u64).map(|i| i.to_le_bytes()).collect();
+        let keypairs: Vec<Keypair> = (0..messages.len())
+            .map(|_| Keypair::generate_with(&mut csprng))
+            .collect();
+        let ts = || messages.iter().map(|m| ctx.bytes(m));
+
+        let signed = keypairs.iter().zip(ts())
+            .map(|(k, t)| k.vrf_sign(t))
+            .collect::<Vec<_>>();
+        let mut outs = signed.iter().map(|(io, _, _)| io.to_preout()).collect::<Vec<VRFPreOut>>();
+        let proofs = signed.iter().map(|(_, _, p)| p.clone()).collect::<Vec<VRFProofBatchable>>();
+        let public_keys = keypairs.iter().map(|k| k.public).collect::<Vec<PublicKey>>();
+
+        let serial = vrf_verify_batch(ts(), &outs, &proofs, &public_keys)
+            .expect("Single-threaded batch VRF verification failed!");
+        let parallel = vrf_verify_batch_parallel(ts(), &outs, &proofs, &public_keys)
+            .expect("Parallel batch VRF verification failed!");
+        assert_eq!(serial, parallel);
+
+        outs.swap(3, 170);
+        assert!(vrf_verify_batch(ts(), &outs, &proofs, &public_keys).is_err());
+        assert!(vrf_verify_batch_parallel(ts(), &outs, &proofs, &public_keys).is_err());
+    }
 }